@@ -0,0 +1,51 @@
+//! Compares read_f32_array against calling read_f32 once per element.
+//!
+//! Run with `cargo bench --bench f32_array`.
+
+use osrs_bytes::ReadExt;
+use std::hint::black_box;
+use std::io::Cursor;
+use std::time::{Duration, Instant};
+
+const COUNT: usize = 4096;
+const ITERATIONS: u32 = 5_000;
+
+fn bulk(buf: &[u8]) -> Vec<f32> {
+    Cursor::new(buf).read_f32_array(COUNT).unwrap()
+}
+
+fn per_element(buf: &[u8]) -> Vec<f32> {
+    let mut rdr = Cursor::new(buf);
+    (0..COUNT).map(|_| rdr.read_f32().unwrap()).collect()
+}
+
+fn measure(name: &str, buf: &[u8], f: fn(&[u8]) -> Vec<f32>) -> Duration {
+    for _ in 0..ITERATIONS / 10 {
+        black_box(f(black_box(buf)));
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f(black_box(buf)));
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{:<12} {:>10.2?} per array of {} floats",
+        name,
+        elapsed / ITERATIONS,
+        COUNT
+    );
+    elapsed
+}
+
+fn main() {
+    let buf: Vec<u8> = (0..COUNT)
+        .flat_map(|i| (i as f32 * 0.5).to_be_bytes())
+        .collect();
+    assert_eq!(bulk(&buf), per_element(&buf));
+    let bulk = measure("bulk", &buf, bulk);
+    let per_element = measure("per element", &buf, per_element);
+    println!(
+        "per element / bulk: {:.2}",
+        per_element.as_secs_f64() / bulk.as_secs_f64()
+    );
+}
@@ -0,0 +1,59 @@
+//! Compares building a packet with PacketBuilder against direct WriteExt calls with `?`.
+//!
+//! Run with `cargo bench --bench packet_builder`.
+
+use osrs_bytes::{PacketBuilder, Result, WriteExt};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const FIELDS: u32 = 1000;
+const ITERATIONS: u32 = 10_000;
+
+fn direct() -> Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    for i in 0..FIELDS {
+        buf.write_u8(i as u8)?;
+        buf.write_u16_smart(black_box(i as u16))?;
+        buf.write_u32_me(i)?;
+    }
+    Ok(buf)
+}
+
+fn builder() -> Result<Vec<u8>> {
+    let mut builder = PacketBuilder::new();
+    for i in 0..FIELDS {
+        builder
+            .put(|w| w.write_u8(i as u8))
+            .put(|w| w.write_u16_smart(black_box(i as u16)))
+            .put(|w| w.write_u32_me(i));
+    }
+    builder.build()
+}
+
+fn measure(name: &str, f: fn() -> Result<Vec<u8>>) -> Duration {
+    for _ in 0..ITERATIONS / 10 {
+        black_box(f().unwrap());
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f().unwrap());
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{:<8} {:>10.2?} per packet of {} fields",
+        name,
+        elapsed / ITERATIONS,
+        FIELDS
+    );
+    elapsed
+}
+
+fn main() {
+    assert_eq!(direct().unwrap(), builder().unwrap());
+    let direct = measure("direct", direct);
+    let builder = measure("builder", builder);
+    println!(
+        "builder / direct: {:.2}",
+        builder.as_secs_f64() / direct.as_secs_f64()
+    );
+}
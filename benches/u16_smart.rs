@@ -0,0 +1,77 @@
+//! Compares read_u16_smart_buffered against read_u16_smart on a Cursor and on a BufReader.
+//!
+//! Run with `cargo bench --bench u16_smart`.
+
+use osrs_bytes::{ReadExt, WriteExt};
+use std::hint::black_box;
+use std::io::{BufReader, Cursor};
+use std::time::{Duration, Instant};
+
+const COUNT: usize = 16384;
+const ITERATIONS: u32 = 2_000;
+
+fn cursor_naive(buf: &[u8]) -> Vec<u16> {
+    let mut rdr = Cursor::new(buf);
+    (0..COUNT).map(|_| rdr.read_u16_smart().unwrap()).collect()
+}
+
+fn cursor_fast(buf: &[u8]) -> Vec<u16> {
+    let mut rdr = Cursor::new(buf);
+    (0..COUNT)
+        .map(|_| rdr.read_u16_smart_buffered().unwrap())
+        .collect()
+}
+
+fn buf_reader_naive(buf: &[u8]) -> Vec<u16> {
+    let mut rdr = BufReader::new(buf);
+    (0..COUNT).map(|_| rdr.read_u16_smart().unwrap()).collect()
+}
+
+fn buf_reader_fast(buf: &[u8]) -> Vec<u16> {
+    let mut rdr = BufReader::new(buf);
+    (0..COUNT)
+        .map(|_| rdr.read_u16_smart_buffered().unwrap())
+        .collect()
+}
+
+fn measure(name: &str, buf: &[u8], f: fn(&[u8]) -> Vec<u16>) -> Duration {
+    for _ in 0..ITERATIONS / 10 {
+        black_box(f(black_box(buf)));
+    }
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f(black_box(buf)));
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{:<18} {:>10.2?} per array of {} smarts",
+        name,
+        elapsed / ITERATIONS,
+        COUNT
+    );
+    elapsed
+}
+
+fn main() {
+    let mut buf = Vec::new();
+    for i in 0..COUNT {
+        buf.write_u16_smart((i * 7 % 32768) as u16).unwrap();
+    }
+    let expected = cursor_naive(&buf);
+    assert_eq!(cursor_fast(&buf), expected);
+    assert_eq!(buf_reader_naive(&buf), expected);
+    assert_eq!(buf_reader_fast(&buf), expected);
+
+    let naive = measure("cursor naive", &buf, cursor_naive);
+    let fast = measure("cursor fast", &buf, cursor_fast);
+    println!(
+        "cursor naive / fast: {:.2}",
+        naive.as_secs_f64() / fast.as_secs_f64()
+    );
+    let naive = measure("buf reader naive", &buf, buf_reader_naive);
+    let fast = measure("buf reader fast", &buf, buf_reader_fast);
+    println!(
+        "buf reader naive / fast: {:.2}",
+        naive.as_secs_f64() / fast.as_secs_f64()
+    );
+}
@@ -0,0 +1,311 @@
+use std::io::{Error, ErrorKind, Read, Result};
+
+/// A reader that undoes the add transform on every byte read from the inner reader.
+///
+/// Each byte has 128 subtracted from it, the same as [`ReadExt::read_u8_add`](crate::ReadExt::read_u8_add). A whole section encoded with add can therefore be read with the plain methods such as `read_u16`. Since adding and subtracting 128 are the same modulo 256, this also applies the add transform to plain bytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io::Cursor;
+/// use osrs_bytes::{AddReader, ReadExt};
+///
+/// let buf = vec![194, 217];
+/// let mut rdr = AddReader::new(Cursor::new(buf.clone()));
+/// let manual = u16::from_be_bytes([buf[0].wrapping_sub(128), buf[1].wrapping_sub(128)]);
+/// assert_eq!(rdr.read_u16().unwrap(), manual);
+/// assert_eq!(manual, 16985);
+/// ```
+#[derive(Debug)]
+pub struct AddReader<R> {
+    inner: R,
+}
+
+impl<R: Read> AddReader<R> {
+    /// Creates a new add reader wrapping `inner`.
+    pub fn new(inner: R) -> Self {
+        Self { inner }
+    }
+
+    /// Returns the inner reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for AddReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let n = self.inner.read(buf)?;
+        for b in &mut buf[..n] {
+            *b = b.wrapping_sub(128);
+        }
+        Ok(n)
+    }
+}
+
+/// A reader that counts the bytes read through it.
+///
+/// A [`CountingReader::checkpoint`] taken before a sequence of reads can be passed to [`CountingReader::bytes_since`] to see how many bytes that sequence consumed.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io::Cursor;
+/// use osrs_bytes::{CountingReader, ReadExt};
+///
+/// let mut rdr = CountingReader::new(Cursor::new(vec![1, 0, 2, 0x80, 0x80, 104, 105, 0, 7]));
+/// rdr.read_u8().unwrap();
+/// let checkpoint = rdr.checkpoint();
+/// rdr.read_u16().unwrap();
+/// rdr.read_u16_smart().unwrap();
+/// rdr.read_string_cp1252().unwrap();
+/// assert_eq!(rdr.bytes_since(checkpoint), 7);
+/// assert_eq!(rdr.bytes_read(), 8);
+/// ```
+#[derive(Debug)]
+pub struct CountingReader<R> {
+    inner: R,
+    count: usize,
+}
+
+impl<R: Read> CountingReader<R> {
+    /// Creates a new counting reader wrapping `inner`.
+    pub fn new(inner: R) -> Self {
+        Self { inner, count: 0 }
+    }
+
+    /// Returns the total number of bytes read.
+    pub fn bytes_read(&self) -> usize {
+        self.count
+    }
+
+    /// Returns a checkpoint of the current position.
+    pub fn checkpoint(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the number of bytes read since the checkpoint was taken.
+    pub fn bytes_since(&self, checkpoint: usize) -> usize {
+        self.count - checkpoint
+    }
+
+    /// Returns the inner reader.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.count += n;
+        Ok(n)
+    }
+}
+
+/// A hash or checksum that can be updated with data as it arrives, see [`DigestReader`].
+pub trait Digest {
+    /// Adds `data` to the digest.
+    fn update(&mut self, data: &[u8]);
+}
+
+impl<D: Digest + ?Sized> Digest for &mut D {
+    fn update(&mut self, data: &[u8]) {
+        (**self).update(data)
+    }
+}
+
+/// A reader that feeds every byte read through it into a [`Digest`].
+///
+/// This lets a large file be verified while it is parsed or streamed, without keeping a copy of it, see also [`ReadExt::read_and_digest`](crate::ReadExt::read_and_digest).
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io::{Cursor, Read};
+/// use osrs_bytes::{Digest, DigestReader, ReadExt};
+///
+/// struct Sum(u64);
+///
+/// impl Digest for Sum {
+///     fn update(&mut self, data: &[u8]) {
+///         self.0 += data.iter().map(|&b| b as u64).sum::<u64>();
+///     }
+/// }
+///
+/// let data: Vec<u8> = (0..=255).cycle().take(10_000).collect();
+/// let mut one_shot = Sum(0);
+/// one_shot.update(&data);
+///
+/// let mut rdr = DigestReader::new(Cursor::new(data), Sum(0));
+/// rdr.read_u16().unwrap();
+/// let mut rest = Vec::new();
+/// rdr.read_to_end(&mut rest).unwrap();
+/// assert_eq!(rdr.digest().0, one_shot.0);
+/// ```
+#[derive(Debug)]
+pub struct DigestReader<R, D> {
+    inner: R,
+    digest: D,
+}
+
+impl<R: Read, D: Digest> DigestReader<R, D> {
+    /// Creates a new digest reader wrapping `inner`.
+    pub fn new(inner: R, digest: D) -> Self {
+        Self { inner, digest }
+    }
+
+    /// Returns the digest of the bytes read so far.
+    pub fn digest(&self) -> &D {
+        &self.digest
+    }
+
+    /// Returns the inner reader and the digest.
+    pub fn into_parts(self) -> (R, D) {
+        (self.inner, self.digest)
+    }
+}
+
+impl<R: Read, D: Digest> Read for DigestReader<R, D> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.digest.update(&buf[..n]);
+        Ok(n)
+    }
+}
+
+/// A reader limited to a block of bytes of the inner reader, see [`ReadExt::read_block`](crate::ReadExt::read_block).
+///
+/// Reading past the end of the block behaves like reaching the end of the stream. Call [`BlockReader::finish`] to skip the bytes left in the block, so the inner reader resumes right after it. Dropping the block reader also skips them, but only as a best effort: an error or an early end of the inner reader is silently ignored and leaves the inner reader inside the block.
+///
+/// [`BlockReader::finish`] is the only way to learn how many bytes were left over. `Drop` cannot return a value, and it does not panic on leftover bytes, because leaving a block early, for example with `?` on an error, is a normal way to drop it. Use [`BlockReader::remaining`] before dropping, or `finish`, when a parser must consume its whole block.
+///
+/// # Examples
+///
+/// Dropping skips the leftover bytes without reporting them.
+///
+/// ```rust
+/// use std::io::Cursor;
+/// use osrs_bytes::ReadExt;
+///
+/// let mut rdr = Cursor::new(vec![1, 2, 3, 4, 42]);
+/// let mut block = rdr.read_block(4);
+/// assert_eq!(block.read_u8().unwrap(), 1);
+/// assert_eq!(block.remaining(), 3);
+/// drop(block);
+/// assert_eq!(rdr.read_u8().unwrap(), 42);
+/// ```
+///
+/// An inner reader that ends inside the block is not an error on drop, and its position stays at the end.
+///
+/// ```rust
+/// use std::io::Cursor;
+/// use osrs_bytes::ReadExt;
+///
+/// let mut rdr = Cursor::new(vec![1, 2]);
+/// drop(rdr.read_block(5));
+/// assert_eq!(rdr.position(), 2);
+/// ```
+#[derive(Debug)]
+pub struct BlockReader<'a, R: Read + ?Sized> {
+    inner: &'a mut R,
+    remaining: usize,
+}
+
+impl<'a, R: Read + ?Sized> BlockReader<'a, R> {
+    pub(crate) fn new(inner: &'a mut R, len: usize) -> Self {
+        Self {
+            inner,
+            remaining: len,
+        }
+    }
+
+    /// Returns the number of bytes left in the block.
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Skips the bytes left in the block and returns how many were skipped.
+    ///
+    /// The inner reader ending before the block does is an `UnexpectedEof` error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::{Cursor, ErrorKind};
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0, 1, 2, 3, 4, 42]);
+    /// let mut block = rdr.read_block(5);
+    /// block.read_u16().unwrap();
+    /// assert_eq!(block.finish().unwrap(), 3);
+    /// assert_eq!(rdr.read_u8().unwrap(), 42);
+    ///
+    /// let mut rdr = Cursor::new(vec![0, 1]);
+    /// let err = rdr.read_block(5).finish().unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    /// ```
+    ///
+    /// Interrupted reads are retried.
+    ///
+    /// ```rust
+    /// use std::io::{self, Cursor, ErrorKind, Read};
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// struct Flaky(bool, Cursor<Vec<u8>>);
+    ///
+    /// impl Read for Flaky {
+    ///     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    ///         self.0 = !self.0;
+    ///         if self.0 {
+    ///             return Err(ErrorKind::Interrupted.into());
+    ///         }
+    ///         self.1.read(&mut buf[..1])
+    ///     }
+    /// }
+    ///
+    /// let mut rdr = Flaky(false, Cursor::new(vec![1, 2, 3, 42]));
+    /// assert_eq!(rdr.read_block(3).finish().unwrap(), 3);
+    /// assert_eq!(rdr.1.position(), 3);
+    /// ```
+    pub fn finish(mut self) -> Result<usize> {
+        self.skip_remaining()
+    }
+
+    fn skip_remaining(&mut self) -> Result<usize> {
+        let mut buf = [0; 64];
+        let mut skipped = 0;
+        while self.remaining > 0 {
+            match self.read(&mut buf) {
+                Ok(0) => {
+                    return Err(Error::new(
+                        ErrorKind::UnexpectedEof,
+                        format!(
+                            "Failed skipping block, {} bytes left after the end of the stream",
+                            self.remaining
+                        ),
+                    ))
+                }
+                Ok(n) => skipped += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(skipped)
+    }
+}
+
+impl<R: Read + ?Sized> Read for BlockReader<'_, R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let len = buf.len().min(self.remaining);
+        let n = self.inner.read(&mut buf[..len])?;
+        self.remaining -= n;
+        Ok(n)
+    }
+}
+
+impl<R: Read + ?Sized> Drop for BlockReader<'_, R> {
+    fn drop(&mut self) {
+        let _ = self.skip_remaining();
+    }
+}
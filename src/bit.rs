@@ -0,0 +1,276 @@
+use crate::{OsrsError, Result};
+use std::io::{Read, Write};
+
+/// A reader for bit access mode, reading values of any width from 1 to 32 bits.
+///
+/// Bits are read most significant first and values may span byte boundaries. A byte is only read from the inner reader once its first bit is needed.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io::Cursor;
+/// use osrs_bytes::BitReader;
+///
+/// let mut rdr = BitReader::new(Cursor::new(vec![0b1010_1100, 0b0011_0101, 0b1111_0000, 0b1000_1000]));
+/// assert_eq!(rdr.read_bits(1).unwrap(), 0b1);
+/// assert_eq!(rdr.read_bits(7).unwrap(), 0b010_1100);
+/// assert_eq!(rdr.read_bits(8).unwrap(), 0b0011_0101);
+/// assert_eq!(rdr.read_bits(13).unwrap(), 0b1111_0000_1000_1);
+/// assert_eq!(rdr.into_inner().position(), 4);
+/// ```
+///
+/// Reading past the end of the inner reader is an error.
+///
+/// ```rust
+/// use std::io::Cursor;
+/// use osrs_bytes::BitReader;
+///
+/// let mut rdr = BitReader::new(Cursor::new(vec![0xFF]));
+/// assert_eq!(rdr.read_bits(4).unwrap(), 0xF);
+/// assert!(rdr.read_bits(5).is_err());
+/// ```
+#[derive(Debug)]
+pub struct BitReader<R> {
+    inner: R,
+    byte: u8,
+    remaining: u32,
+}
+
+impl<R: Read> BitReader<R> {
+    /// Creates a new bit reader wrapping `inner`.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            byte: 0,
+            remaining: 0,
+        }
+    }
+
+    /// Reads `count` bits as an unsigned value.
+    ///
+    /// `count` must be from 1 to 32, otherwise this is an error.
+    pub fn read_bits(&mut self, count: u32) -> Result<u32> {
+        if !(1..=32).contains(&count) {
+            return Err(OsrsError::Other(format!(
+                "Failed reading bits, count is {}",
+                count
+            )));
+        }
+
+        let mut value: u64 = 0;
+        let mut left = count;
+        while left > 0 {
+            if self.remaining == 0 {
+                let mut buf = [0; 1];
+                self.inner.read_exact(&mut buf)?;
+                self.byte = buf[0];
+                self.remaining = 8;
+            }
+            let take = left.min(self.remaining);
+            let bits = (self.byte >> (self.remaining - take)) as u64 & ((1 << take) - 1);
+            value = value << take | bits;
+            self.remaining -= take;
+            left -= take;
+        }
+        Ok(value as u32)
+    }
+
+    /// Reads a bit smart, written by [`BitWriter::write_smart`](crate::BitWriter::write_smart).
+    ///
+    /// A flag bit of 0 is followed by the value in `small_bits` bits, and a flag bit of 1 by the value in `large_bits` bits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::BitReader;
+    ///
+    /// let mut rdr = BitReader::new(Cursor::new(vec![0b0010_1110, 0b0000_0110, 0b0100_0000]));
+    /// assert_eq!(rdr.read_smart(5, 13).unwrap(), 11);
+    /// assert_eq!(rdr.read_smart(5, 13).unwrap(), 100);
+    /// ```
+    pub fn read_smart(&mut self, small_bits: u32, large_bits: u32) -> Result<u32> {
+        if self.read_bits(1)? == 0 {
+            self.read_bits(small_bits)
+        } else {
+            self.read_bits(large_bits)
+        }
+    }
+
+    /// Returns the inner reader, discarding any unread bits of the current byte.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+/// A writer for bit access mode, writing values of any width from 1 to 32 bits.
+///
+/// Bits are written most significant first and values may span byte boundaries. Complete bytes are written to the inner writer as soon as they are filled, and [`BitWriter::align`] pads the final partial byte with zeros.
+///
+/// # Examples
+///
+/// ```rust
+/// use osrs_bytes::BitWriter;
+///
+/// let mut wtr = BitWriter::new(Vec::new());
+/// wtr.write_bits(0b101, 3).unwrap();
+/// wtr.write_bits(0b1_1110_0001, 9).unwrap();
+/// assert_eq!(wtr.finish().unwrap(), vec![0b1011_1110, 0b0001_0000]);
+/// ```
+///
+/// Values written with a [`BitWriter`] read back the same with a [`BitReader`](crate::BitReader).
+///
+/// ```rust
+/// use std::io::Cursor;
+/// use osrs_bytes::{BitReader, BitWriter};
+///
+/// let values = [(1, 1), (77, 7), (3, 2), (8191, 13), (0, 5), (0xDEADBEEF, 32), (42, 11)];
+/// let mut wtr = BitWriter::new(Vec::new());
+/// for (value, count) in values {
+///     wtr.write_bits(value, count).unwrap();
+/// }
+/// let buf = wtr.finish().unwrap();
+/// assert_eq!(buf.len(), 9);
+///
+/// let mut rdr = BitReader::new(Cursor::new(buf));
+/// for (value, count) in values {
+///     assert_eq!(rdr.read_bits(count).unwrap(), value);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct BitWriter<W> {
+    inner: W,
+    byte: u8,
+    filled: u32,
+}
+
+impl<W: Write> BitWriter<W> {
+    /// Creates a new bit writer wrapping `inner`.
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            byte: 0,
+            filled: 0,
+        }
+    }
+
+    /// Writes the low `count` bits of `value`.
+    ///
+    /// `count` must be from 1 to 32 and `value` must fit in `count` bits, otherwise this is an error and nothing is written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::BitWriter;
+    ///
+    /// let mut wtr = BitWriter::new(Vec::new());
+    /// assert!(wtr.write_bits(8, 3).is_err());
+    /// assert!(wtr.write_bits(0, 33).is_err());
+    /// assert_eq!(wtr.finish().unwrap(), vec![]);
+    /// ```
+    pub fn write_bits(&mut self, value: u32, count: u32) -> Result<()> {
+        if !(1..=32).contains(&count) {
+            return Err(OsrsError::Other(format!(
+                "Failed writing bits, count is {}",
+                count
+            )));
+        }
+        if (value as u64) >> count != 0 {
+            return Err(OsrsError::Other(format!(
+                "Failed writing bits, value {} does not fit in {} bits",
+                value, count
+            )));
+        }
+
+        let mut left = count;
+        while left > 0 {
+            let take = left.min(8 - self.filled);
+            let bits = (value >> (left - take)) as u8 & ((1u16 << take) - 1) as u8;
+            self.byte |= bits << (8 - self.filled - take);
+            self.filled += take;
+            left -= take;
+            if self.filled == 8 {
+                self.inner.write_all(&[self.byte])?;
+                self.byte = 0;
+                self.filled = 0;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a bit smart, a flag bit selecting the width followed by `value` in that many bits.
+    ///
+    /// A `value` that fits in `small_bits` bits is written with a flag bit of 0 and `small_bits` value bits, and any other `value` with a flag bit of 1 and `large_bits` value bits. The widths must be from 1 to 32 with `small_bits` at most `large_bits`, and `value` must fit in `large_bits` bits, otherwise this is an error and nothing is written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::BitWriter;
+    ///
+    /// let mut wtr = BitWriter::new(Vec::new());
+    /// wtr.write_smart(11, 5, 13).unwrap();
+    /// wtr.write_smart(100, 5, 13).unwrap();
+    /// assert_eq!(wtr.finish().unwrap(), vec![0b0010_1110, 0b0000_0110, 0b0100_0000]);
+    /// ```
+    ///
+    /// Values round-trip through [`BitReader::read_smart`](crate::BitReader::read_smart), on either side of the small width.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{BitReader, BitWriter};
+    ///
+    /// let values = [0, 1, 31, 32, 4095, 8191];
+    /// let mut wtr = BitWriter::new(Vec::new());
+    /// for value in values {
+    ///     wtr.write_smart(value, 5, 13).unwrap();
+    /// }
+    /// assert!(wtr.write_smart(8192, 5, 13).is_err());
+    /// assert!(wtr.write_smart(0, 14, 13).is_err());
+    /// let buf = wtr.finish().unwrap();
+    ///
+    /// let mut rdr = BitReader::new(Cursor::new(buf));
+    /// for value in values {
+    ///     assert_eq!(rdr.read_smart(5, 13).unwrap(), value);
+    /// }
+    /// ```
+    pub fn write_smart(&mut self, value: u32, small_bits: u32, large_bits: u32) -> Result<()> {
+        if !(1..=32).contains(&large_bits) || !(1..=large_bits).contains(&small_bits) {
+            return Err(OsrsError::Other(format!(
+                "Failed writing bit smart, widths are {} and {}",
+                small_bits, large_bits
+            )));
+        }
+        if (value as u64) >> large_bits != 0 {
+            return Err(OsrsError::Other(format!(
+                "Failed writing bit smart, value {} does not fit in {} bits",
+                value, large_bits
+            )));
+        }
+
+        if (value as u64) >> small_bits == 0 {
+            self.write_bits(0, 1)?;
+            self.write_bits(value, small_bits)
+        } else {
+            self.write_bits(1, 1)?;
+            self.write_bits(value, large_bits)
+        }
+    }
+
+    /// Pads the current partial byte with zeros and writes it, so the next bits start on a new byte.
+    ///
+    /// Does nothing if the bits written so far end on a byte boundary.
+    pub fn align(&mut self) -> Result<()> {
+        if self.filled > 0 {
+            self.inner.write_all(&[self.byte])?;
+            self.byte = 0;
+            self.filled = 0;
+        }
+        Ok(())
+    }
+
+    /// Aligns to the next byte and returns the inner writer.
+    pub fn finish(mut self) -> Result<W> {
+        self.align()?;
+        Ok(self.inner)
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{BitWriter, OsrsError, Result};
+use std::mem;
+
+/// A packet buffer that records the first failed write instead of returning it from every call.
+///
+/// Once a write has failed, later writes are skipped and the error is returned from [`PacketBuilder::build`].
+///
+/// # Examples
+///
+/// ```rust
+/// use osrs_bytes::{PacketBuilder, WriteExt};
+///
+/// let mut builder = PacketBuilder::new();
+/// builder.put(|w| w.write_u8(1)).put(|w| w.write_u16_smart(986));
+/// assert_eq!(builder.build().unwrap(), vec![1, 131, 218]);
+/// ```
+///
+/// ```rust
+/// use osrs_bytes::{PacketBuilder, WriteExt};
+///
+/// let mut builder = PacketBuilder::new();
+/// builder
+///     .put(|w| w.write_u8(1))
+///     .put(|w| w.write_u16_smart(40000))
+///     .put(|w| w.write_u16_smart(50000))
+///     .put(|w| w.write_u8(2));
+/// assert_eq!(builder.len(), 1);
+/// let err = builder.build().unwrap_err();
+/// assert_eq!(err.to_string(), "Failed writing smart, value is 40000");
+/// ```
+#[derive(Debug, Default)]
+pub struct PacketBuilder {
+    buf: Vec<u8>,
+    error: Option<OsrsError>,
+}
+
+impl PacketBuilder {
+    /// Creates an empty packet builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Runs a write against the buffer, unless an earlier write has failed.
+    pub fn put<F: FnOnce(&mut Vec<u8>) -> Result<()>>(&mut self, f: F) -> &mut Self {
+        if self.error.is_none() {
+            if let Err(e) = f(&mut self.buf) {
+                self.error = Some(e);
+            }
+        }
+        self
+    }
+
+    /// Returns the number of bytes written so far.
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns true if no bytes have been written.
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Returns the packet, or the first error that occurred while building it.
+    pub fn build(self) -> Result<Vec<u8>> {
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(self.buf),
+        }
+    }
+}
+
+/// A packet buffer that switches between byte access mode and bit access mode.
+///
+/// The builder starts in byte access mode. [`GamePacketBuilder::switch_to_bits`] continues in bit access mode from the current byte, and [`GamePacketBuilder::switch_to_bytes`] pads the last partial byte with zeros so the following bytes start on a byte boundary.
+///
+/// # Examples
+///
+/// ```rust
+/// use osrs_bytes::{GamePacketBuilder, WriteExt};
+///
+/// let mut builder = GamePacketBuilder::new();
+/// builder.switch_to_bits().write_bits(0b101, 3).unwrap();
+/// builder.switch_to_bytes().unwrap().write_u16(0x1234).unwrap();
+/// builder.switch_to_bits().write_bits(0b1, 1).unwrap();
+/// assert_eq!(builder.build().unwrap(), vec![0b1010_0000, 0x12, 0x34, 0b1000_0000]);
+/// ```
+///
+/// Bits ending on a byte boundary are not padded.
+///
+/// ```rust
+/// use osrs_bytes::{GamePacketBuilder, WriteExt};
+///
+/// let mut builder = GamePacketBuilder::new();
+/// builder.switch_to_bytes().unwrap().write_u8(7).unwrap();
+/// builder.switch_to_bits().write_bits(0xABC, 12).unwrap();
+/// builder.switch_to_bits().write_bits(0xD, 4).unwrap();
+/// builder.align_bits().unwrap();
+/// builder.align_bits().unwrap();
+/// builder.switch_to_bytes().unwrap().write_u8(8).unwrap();
+/// assert_eq!(builder.build().unwrap(), vec![7, 0xAB, 0xCD, 8]);
+/// ```
+#[derive(Debug, Default)]
+pub struct GamePacketBuilder {
+    buf: Vec<u8>,
+    bits: Option<BitWriter<Vec<u8>>>,
+}
+
+impl GamePacketBuilder {
+    /// Creates an empty packet builder in byte access mode.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Switches to bit access mode, unless already in it, and returns the bit writer.
+    pub fn switch_to_bits(&mut self) -> &mut BitWriter<Vec<u8>> {
+        self.bits
+            .get_or_insert_with(|| BitWriter::new(mem::take(&mut self.buf)))
+    }
+
+    /// Pads the last partial byte with zeros and switches to byte access mode.
+    ///
+    /// Does nothing in byte access mode.
+    pub fn align_bits(&mut self) -> Result<()> {
+        if let Some(bits) = self.bits.take() {
+            self.buf = bits.finish()?;
+        }
+        Ok(())
+    }
+
+    /// Switches to byte access mode like [`GamePacketBuilder::align_bits`] and returns the byte buffer.
+    pub fn switch_to_bytes(&mut self) -> Result<&mut Vec<u8>> {
+        self.align_bits()?;
+        Ok(&mut self.buf)
+    }
+
+    /// Aligns the bits written so far and returns the packet.
+    pub fn build(mut self) -> Result<Vec<u8>> {
+        self.align_bits()?;
+        Ok(self.buf)
+    }
+}
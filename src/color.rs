@@ -0,0 +1,61 @@
+//! HSL16 colors, as used for model faces and recolors.
+//!
+//! An HSL16 color holds the hue in the top 6 bits, the saturation in the next 3 and the lightness in the low 7. The conversion is the client's palette conversion without its brightness adjustment.
+
+/// Converts an HSL16 color to a 24-bit RGB color.
+///
+/// # Examples
+///
+/// ```rust
+/// use osrs_bytes::color;
+///
+/// assert_eq!(color::hsl_to_rgb(0x1A2B), 0x866025);
+/// assert_eq!(color::hsl_to_rgb(0), 0x000000);
+/// assert_eq!(color::hsl_to_rgb(127), 0xFEFDFD);
+/// ```
+pub fn hsl_to_rgb(hsl: u16) -> u32 {
+    let hue = (hsl >> 10 & 0x3F) as f64 / 64.0 + 0.0078125;
+    let saturation = (hsl >> 7 & 0x7) as f64 / 8.0 + 0.0625;
+    let lightness = (hsl & 0x7F) as f64 / 128.0;
+
+    let q = if lightness < 0.5 {
+        lightness * (1.0 + saturation)
+    } else {
+        lightness + saturation - lightness * saturation
+    };
+    let p = 2.0 * lightness - q;
+    let channel = |t: f64| {
+        let t = if t < 0.0 {
+            t + 1.0
+        } else if t > 1.0 {
+            t - 1.0
+        } else {
+            t
+        };
+        let value = if 6.0 * t < 1.0 {
+            p + (q - p) * 6.0 * t
+        } else if 2.0 * t < 1.0 {
+            q
+        } else if 3.0 * t < 2.0 {
+            p + (q - p) * (2.0 / 3.0 - t) * 6.0
+        } else {
+            p
+        };
+        (value * 256.0) as u32
+    };
+
+    channel(hue + 1.0 / 3.0) << 16 | channel(hue) << 8 | channel(hue - 1.0 / 3.0)
+}
+
+/// Converts a slice of HSL16 colors to 24-bit RGB colors, see [`hsl_to_rgb`].
+///
+/// # Examples
+///
+/// ```rust
+/// use osrs_bytes::color;
+///
+/// assert_eq!(color::hsl_array_to_rgb(&[0, 0x1A2B]), vec![0x000000, 0x866025]);
+/// ```
+pub fn hsl_array_to_rgb(colors: &[u16]) -> Vec<u32> {
+    colors.iter().map(|&hsl| hsl_to_rgb(hsl)).collect()
+}
@@ -0,0 +1,172 @@
+//! Definitions decoded from the cache.
+
+use std::collections::HashMap;
+
+/// A varbit definition, a range of bits within a varp.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Varbit {
+    /// The varp holding the bits
+    pub index: u16,
+    /// The least significant bit
+    pub lsb: u8,
+    /// The most significant bit
+    pub msb: u8,
+}
+
+/// The transforms of an npc or object, selected by the value of a varbit or varp.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Transforms {
+    /// The varbit selecting the transform, if any
+    pub varbit: Option<u16>,
+    /// The varp selecting the transform, if any
+    pub varp: Option<u16>,
+    /// The alternate ids, indexed by the varbit or varp value
+    pub ids: Vec<Option<u16>>,
+}
+
+/// The ids linking an item to its noted and placeholder variants.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ItemLinks {
+    /// Opcode 97, the noted or unnoted counterpart
+    pub note_link: Option<u16>,
+    /// Opcode 98, the template used for the noted variant
+    pub note_template: Option<u16>,
+    /// Opcode 148, the placeholder or real counterpart
+    pub placeholder_link: Option<u16>,
+    /// Opcode 149, the template used for the placeholder variant
+    pub placeholder_template: Option<u16>,
+}
+
+/// The models shown when an item is equipped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ItemEquipModels {
+    /// Opcode 23, the primary male model
+    pub male_model0: Option<u16>,
+    /// Opcode 23, the vertical offset of the male models
+    pub male_offset: i8,
+    /// Opcode 24
+    pub male_model1: Option<u16>,
+    /// Opcode 78
+    pub male_model2: Option<u16>,
+    /// Opcode 25, the primary female model
+    pub female_model0: Option<u16>,
+    /// Opcode 25, the vertical offset of the female models
+    pub female_offset: i8,
+    /// Opcode 26
+    pub female_model1: Option<u16>,
+    /// Opcode 79
+    pub female_model2: Option<u16>,
+    /// Opcode 90
+    pub male_head_model0: Option<u16>,
+    /// Opcode 92
+    pub male_head_model1: Option<u16>,
+    /// Opcode 91
+    pub female_head_model0: Option<u16>,
+    /// Opcode 93
+    pub female_head_model1: Option<u16>,
+}
+
+/// A sound effect envelope.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Envelope {
+    /// The waveform
+    pub form: u8,
+    /// The value at the start of the envelope
+    pub start: i32,
+    /// The value at the end of the envelope
+    pub end: i32,
+    /// The `(duration, phase)` of each segment
+    pub segments: Vec<(u16, u16)>,
+}
+
+/// An enum definition, a map from keys to values of the given types.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EnumConfig {
+    /// Opcode 1, the type char of the keys
+    pub key_type: char,
+    /// Opcode 2, the type char of the values
+    pub value_type: char,
+    /// Opcode 3, the value for missing keys when the values are strings
+    pub default_string: String,
+    /// Opcode 4, the value for missing keys when the values are ints
+    pub default_int: i32,
+    /// Opcode 5, the string values
+    pub string_values: HashMap<i32, String>,
+    /// Opcode 6, the int values
+    pub int_values: HashMap<i32, i32>,
+}
+
+/// A spotanim definition, a graphic played on a tile or on top of an entity.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpotAnim {
+    /// Opcode 1, the model
+    pub model: u16,
+    /// Opcode 2, the animation, if any
+    pub animation: Option<u16>,
+    /// Opcode 4, the horizontal scale, where 128 is unscaled
+    pub resize_x: u16,
+    /// Opcode 5, the vertical scale, where 128 is unscaled
+    pub resize_y: u16,
+    /// Opcode 6, the rotation in degrees
+    pub rotation: u16,
+    /// Opcode 7, the ambient lighting
+    pub ambient: u8,
+    /// Opcode 8, the lighting contrast
+    pub contrast: u8,
+    /// Opcode 40, the `(source, destination)` HSL16 colors
+    pub recolors: Vec<(u16, u16)>,
+    /// Opcode 41, the `(source, destination)` texture ids
+    pub retextures: Vec<(u16, u16)>,
+}
+
+impl Default for SpotAnim {
+    fn default() -> Self {
+        Self {
+            model: 0,
+            animation: None,
+            resize_x: 128,
+            resize_y: 128,
+            rotation: 0,
+            ambient: 0,
+            contrast: 0,
+            recolors: Vec::new(),
+            retextures: Vec::new(),
+        }
+    }
+}
+
+/// A health bar definition.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HitBar {
+    /// Opcode 2
+    pub int1: u8,
+    /// Opcode 3
+    pub int2: u8,
+    /// Opcode 4 sets this to 0 and opcode 11 reads it as a u16, the number of client cycles the bar stays visible, if limited
+    pub duration: Option<u16>,
+    /// Opcode 5, the number of client cycles the bar takes to fade out
+    pub fade_duration: u16,
+    /// Opcode 7, the sprite for the remaining health, if any
+    pub front_sprite: Option<u32>,
+    /// Opcode 8, the sprite behind it, if any
+    pub back_sprite: Option<u32>,
+    /// Opcode 14, the width in pixels
+    pub width: u8,
+    /// Opcode 15, the padding on each side of the front sprite
+    pub padding: u8,
+}
+
+impl Default for HitBar {
+    fn default() -> Self {
+        Self {
+            int1: 255,
+            int2: 255,
+            duration: None,
+            fade_duration: 70,
+            front_sprite: None,
+            back_sprite: None,
+            width: 30,
+            padding: 0,
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt;
+use std::io;
+
+/// The result type of the crate.
+pub type Result<T> = std::result::Result<T, OsrsError>;
+
+/// The error type of the crate.
+///
+/// I/O errors are wrapped in [`OsrsError::Io`], so `?` works on the standard library's reads and writes. Converting back into an [`io::Error`] unwraps them again, and wraps every other variant with [`io::ErrorKind::Other`].
+///
+/// # Examples
+///
+/// ```rust
+/// use osrs_bytes::{OsrsError, WriteExt};
+///
+/// let mut wtr = Vec::new();
+/// assert!(matches!(
+///     wtr.write_u16_smart(40000),
+///     Err(OsrsError::SmartOutOfRange(40000))
+/// ));
+/// ```
+///
+/// ```rust
+/// use std::io::{Cursor, ErrorKind};
+/// use osrs_bytes::{OsrsError, ReadExt};
+///
+/// let mut rdr = Cursor::new(vec![1]);
+/// let err = rdr.read_u16().unwrap_err();
+/// assert!(matches!(&err, OsrsError::Io(e) if e.kind() == ErrorKind::UnexpectedEof));
+/// assert_eq!(std::io::Error::from(err).kind(), ErrorKind::UnexpectedEof);
+/// ```
+#[derive(Debug)]
+pub enum OsrsError {
+    /// An error from the underlying reader or writer
+    Io(io::Error),
+    /// A value that does not fit in the smart being written
+    SmartOutOfRange(i64),
+    /// A version byte with no known format
+    InvalidVersionByte(u8),
+    /// An opcode that is not part of the definition being read
+    InvalidOpcode(u8),
+    /// A bool byte that is neither 0 nor 1
+    InvalidBool(u8),
+    /// Any other value that cannot be read or written
+    Other(String),
+}
+
+impl fmt::Display for OsrsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OsrsError::Io(e) => e.fmt(f),
+            OsrsError::SmartOutOfRange(n) => write!(f, "Failed writing smart, value is {}", n),
+            OsrsError::InvalidVersionByte(v) => {
+                write!(f, "Failed reading versioned data, version is {}", v)
+            }
+            OsrsError::InvalidOpcode(opcode) => {
+                write!(f, "Failed reading definition, opcode is {}", opcode)
+            }
+            OsrsError::InvalidBool(b) => write!(f, "Failed reading bool, value is {}", b),
+            OsrsError::Other(msg) => f.write_str(msg),
+        }
+    }
+}
+
+impl std::error::Error for OsrsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            OsrsError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for OsrsError {
+    fn from(e: io::Error) -> Self {
+        OsrsError::Io(e)
+    }
+}
+
+impl From<OsrsError> for io::Error {
+    fn from(e: OsrsError) -> Self {
+        match e {
+            OsrsError::Io(e) => e,
+            e => io::Error::other(e),
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+
+/// A handle to a string stored in a [`StringInterner`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct InternedString(u32);
+
+/// Stores each distinct string once and hands out cheap handles to them.
+///
+/// # Examples
+///
+/// ```rust
+/// use osrs_bytes::StringInterner;
+///
+/// let mut interner = StringInterner::new();
+/// let a = interner.intern("Take".to_owned());
+/// let b = interner.intern("Take".to_owned());
+/// assert_eq!(a, b);
+/// assert_eq!(interner.resolve(a), "Take");
+/// assert_eq!(interner.len(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct StringInterner {
+    handles: HashMap<Rc<str>, InternedString>,
+    strings: Vec<Rc<str>>,
+}
+
+impl StringInterner {
+    /// Creates an empty interner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the handle for `s`, storing it if it has not been seen before.
+    pub fn intern(&mut self, s: String) -> InternedString {
+        if let Some(handle) = self.handles.get(s.as_str()) {
+            return *handle;
+        }
+        let handle = InternedString(self.strings.len() as u32);
+        let s: Rc<str> = s.into();
+        self.strings.push(s.clone());
+        self.handles.insert(s, handle);
+        handle
+    }
+
+    /// Returns the string for a handle from this interner.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the handle was returned by a different interner.
+    pub fn resolve(&self, handle: InternedString) -> &str {
+        &self.strings[handle.0 as usize]
+    }
+
+    /// Returns the number of distinct strings stored.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns true if no strings are stored.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+}
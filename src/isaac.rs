@@ -0,0 +1,142 @@
+const GOLDEN_RATIO: u32 = 0x9E3779B9;
+
+/// The ISAAC random number generator the client uses to offset packet opcodes.
+///
+/// The generator is seeded with four dwords, matching the client. Like the client, each batch of 256 results is handed out from the last result to the first.
+///
+/// # Examples
+///
+/// ```rust
+/// use osrs_bytes::Isaac;
+///
+/// let mut a = Isaac::new([1, 2, 3, 4]);
+/// let mut b = Isaac::new([1, 2, 3, 4]);
+/// assert_eq!(a.next_u32(), b.next_u32());
+/// assert_ne!(Isaac::new([0; 4]).next_u32(), Isaac::new([1, 0, 0, 0]).next_u32());
+/// ```
+///
+/// With an all-zero seed, the second batch matches the reference output of ISAAC's own test program in reverse.
+///
+/// ```rust
+/// use osrs_bytes::Isaac;
+///
+/// let mut isaac = Isaac::new([0; 4]);
+/// for _ in 0..256 {
+///     isaac.next_u32();
+/// }
+/// let mut batch: Vec<u32> = (0..256).map(|_| isaac.next_u32()).collect();
+/// batch.reverse();
+/// assert_eq!(batch[..4], [0xF650E4C8, 0xE448E96D, 0x98DB2FB4, 0xF5FAD54F]);
+/// ```
+#[derive(Clone)]
+pub struct Isaac {
+    results: [u32; 256],
+    memory: [u32; 256],
+    a: u32,
+    b: u32,
+    c: u32,
+    count: usize,
+}
+
+impl Isaac {
+    /// Creates a generator from the four seed dwords.
+    pub fn new(seed: [u32; 4]) -> Self {
+        let mut isaac = Self {
+            results: [0; 256],
+            memory: [0; 256],
+            a: 0,
+            b: 0,
+            c: 0,
+            count: 0,
+        };
+        isaac.results[..4].copy_from_slice(&seed);
+        isaac.init();
+        isaac
+    }
+
+    /// Returns the next random dword.
+    pub fn next_u32(&mut self) -> u32 {
+        if self.count == 0 {
+            self.generate();
+            self.count = 256;
+        }
+        self.count -= 1;
+        self.results[self.count]
+    }
+
+    /// Returns the next offset to add to an outgoing opcode or subtract from an incoming one.
+    pub fn next_opcode_offset(&mut self) -> u8 {
+        self.next_u32() as u8
+    }
+
+    fn init(&mut self) {
+        let mut s = [GOLDEN_RATIO; 8];
+        for _ in 0..4 {
+            mix(&mut s);
+        }
+        for pass in 0..2 {
+            for i in (0..256).step_by(8) {
+                let src = if pass == 0 {
+                    &self.results
+                } else {
+                    &self.memory
+                };
+                for (j, v) in s.iter_mut().enumerate() {
+                    *v = v.wrapping_add(src[i + j]);
+                }
+                mix(&mut s);
+                self.memory[i..i + 8].copy_from_slice(&s);
+            }
+        }
+        self.generate();
+        self.count = 256;
+    }
+
+    fn generate(&mut self) {
+        self.c = self.c.wrapping_add(1);
+        self.b = self.b.wrapping_add(self.c);
+        for i in 0..256 {
+            let x = self.memory[i];
+            self.a ^= match i % 4 {
+                0 => self.a << 13,
+                1 => self.a >> 6,
+                2 => self.a << 2,
+                _ => self.a >> 16,
+            };
+            self.a = self.memory[(i + 128) % 256].wrapping_add(self.a);
+            let y = self.memory[(x >> 2) as usize % 256]
+                .wrapping_add(self.a)
+                .wrapping_add(self.b);
+            self.memory[i] = y;
+            self.b = self.memory[(y >> 10) as usize % 256].wrapping_add(x);
+            self.results[i] = self.b;
+        }
+    }
+}
+
+fn mix(s: &mut [u32; 8]) {
+    s[0] ^= s[1] << 11;
+    s[3] = s[3].wrapping_add(s[0]);
+    s[1] = s[1].wrapping_add(s[2]);
+    s[1] ^= s[2] >> 2;
+    s[4] = s[4].wrapping_add(s[1]);
+    s[2] = s[2].wrapping_add(s[3]);
+    s[2] ^= s[3] << 8;
+    s[5] = s[5].wrapping_add(s[2]);
+    s[3] = s[3].wrapping_add(s[4]);
+    s[3] ^= s[4] >> 16;
+    s[6] = s[6].wrapping_add(s[3]);
+    s[4] = s[4].wrapping_add(s[5]);
+    s[4] ^= s[5] << 10;
+    s[7] = s[7].wrapping_add(s[4]);
+    s[5] = s[5].wrapping_add(s[6]);
+    s[5] ^= s[6] >> 4;
+    s[0] = s[0].wrapping_add(s[5]);
+    s[6] = s[6].wrapping_add(s[7]);
+    s[6] ^= s[7] << 8;
+    s[1] = s[1].wrapping_add(s[6]);
+    s[7] = s[7].wrapping_add(s[0]);
+    s[7] ^= s[0] >> 9;
+    s[2] = s[2].wrapping_add(s[7]);
+    s[0] = s[0].wrapping_add(s[1]);
+}
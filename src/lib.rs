@@ -4,7 +4,112 @@
 //!
 //! This crate provides Read and Write extensions for working with the data types on any data structure implementing `&[u8]` such as Vec, Cursor etc.
 
-use std::io::{self, Error, ErrorKind, Read, Result, Write};
+mod adapters;
+mod bit;
+mod builder;
+pub mod color;
+pub mod config;
+mod error;
+mod intern;
+mod isaac;
+mod selftest;
+pub mod update;
+pub mod xtea;
+
+pub use adapters::{AddReader, BlockReader, CountingReader, Digest, DigestReader};
+pub use bit::{BitReader, BitWriter};
+pub use builder::{GamePacketBuilder, PacketBuilder};
+pub use error::{OsrsError, Result};
+pub use intern::{InternedString, StringInterner};
+pub use isaac::Isaac;
+pub use selftest::selftest;
+
+use config::{
+    EnumConfig, Envelope, HitBar, ItemEquipModels, ItemLinks, SpotAnim, Transforms, Varbit,
+};
+use std::collections::HashMap;
+use std::io::{self, BufRead, Read, Seek, SeekFrom, Write};
+
+/// A three-valued config flag, stored as a single byte of 0, 1 or 2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tristate {
+    No = 0,
+    Yes = 1,
+    Inherit = 2,
+}
+
+/// The type of operand following a ClientScript opcode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OperandType {
+    Int,
+    Long,
+    Str,
+}
+
+/// A ClientScript instruction operand.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Operand {
+    Int(i32),
+    Long(i64),
+    Str(String),
+}
+
+/// A param or enum value, read according to its type char.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TypedValue {
+    /// Type char `i`
+    Int(i32),
+    /// Type char `s`
+    Str(String),
+    /// Type char `o`, an item id
+    Obj(u32),
+    /// Type char `I`, an interface component id
+    Component(u32),
+}
+
+/// The encoding of a length prefix in front of a collection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LengthPrefix {
+    /// An unsigned byte
+    U8,
+    /// An unsigned short as big endian
+    U16,
+    /// An unsigned short as smart
+    Smart,
+}
+
+/// The byte order of a value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    Big,
+    Little,
+}
+
+/// The size of a game packet, either fixed per opcode or sent in front of the body.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PacketSize {
+    /// A body of exactly this many bytes, with no size prefix
+    Fixed(usize),
+    /// A body of up to 255 bytes, with a u8 size prefix
+    VarByte,
+    /// A body of up to 65535 bytes, with a u16 size prefix
+    VarShort,
+}
+
+/// An object spawned in a zone, see [`ReadExt::read_object_spawn`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ObjectSpawn {
+    pub id: u16,
+    /// The x coordinate within the zone
+    pub x: u8,
+    /// The y coordinate within the zone
+    pub y: u8,
+    pub shape: u8,
+    pub rotation: u8,
+}
+
+/// A reader function for one version of a versioned structure, see [`ReadExt::read_versioned`].
+pub type VersionHandler<R, T> = fn(&mut R) -> Result<T>;
 
 pub trait ReadExt: Read {
     /// Reads an unsigned byte
@@ -115,6 +220,38 @@ pub trait ReadExt: Read {
         Ok(128u8.wrapping_sub(self.read_u8()?))
     }
 
+    /// Reads an unsigned byte multiplied by the given factor
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![25]);
+    /// assert_eq!(rdr.read_u8_scaled(10).unwrap(), 250);
+    /// ```
+    ///
+    /// A product that overflows u32 is an error.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![255]);
+    /// assert!(rdr.read_u8_scaled(u32::MAX / 255 + 1).is_err());
+    /// ```
+    #[inline]
+    fn read_u8_scaled(&mut self, factor: u32) -> Result<u32> {
+        let byte = self.read_u8()?;
+        (byte as u32).checked_mul(factor).ok_or_else(|| {
+            OsrsError::Other(format!(
+                "Failed reading scaled byte, {} times {} overflows",
+                byte, factor
+            ))
+        })
+    }
+
     /// Reads a signed byte
     ///
     /// # Examples
@@ -151,6 +288,23 @@ pub trait ReadExt: Read {
     /// let mut rdr = Cursor::new(vec![128]);
     /// assert_eq!(rdr.read_i8_add().unwrap(), 0);
     /// ```
+    ///
+    /// Round-tripping through [`WriteExt::write_i8_add`].
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// for n in [-128, -1, 0, 1, 127] {
+    ///     wtr.write_i8_add(n).unwrap();
+    /// }
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in [-128, -1, 0, 1, 127] {
+    ///     assert_eq!(rdr.read_i8_add().unwrap(), n);
+    /// }
+    /// ```
     #[inline]
     fn read_i8_add(&mut self) -> Result<i8> {
         Ok(self.read_u8_add()? as i8)
@@ -175,6 +329,23 @@ pub trait ReadExt: Read {
     /// let mut rdr = Cursor::new(vec![42]);
     /// assert_eq!(rdr.read_i8_neg().unwrap(), -42);
     /// ```
+    ///
+    /// Round-tripping through [`WriteExt::write_i8_neg`].
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// for n in [-128, -1, 0, 1, 127] {
+    ///     wtr.write_i8_neg(n).unwrap();
+    /// }
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in [-128, -1, 0, 1, 127] {
+    ///     assert_eq!(rdr.read_i8_neg().unwrap(), n);
+    /// }
+    /// ```
     #[inline]
     fn read_i8_neg(&mut self) -> Result<i8> {
         Ok(self.read_u8_neg()? as i8)
@@ -199,6 +370,23 @@ pub trait ReadExt: Read {
     /// let mut rdr = Cursor::new(vec![42]);
     /// assert_eq!(rdr.read_i8_sub().unwrap(), 86);
     /// ```
+    ///
+    /// Round-tripping through [`WriteExt::write_i8_sub`].
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// for n in [-128, -1, 0, 1, 127] {
+    ///     wtr.write_i8_sub(n).unwrap();
+    /// }
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in [-128, -1, 0, 1, 127] {
+    ///     assert_eq!(rdr.read_i8_sub().unwrap(), n);
+    /// }
+    /// ```
     #[inline]
     fn read_i8_sub(&mut self) -> Result<i8> {
         Ok(self.read_u8_sub()? as i8)
@@ -224,6 +412,69 @@ pub trait ReadExt: Read {
         Ok(buf[0] != 0)
     }
 
+    /// Reads a bool that must be exactly 0 or 1
+    ///
+    /// Any other byte is an [`OsrsError::InvalidBool`] carrying the byte, where [`ReadExt::read_bool`] would read it as true.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{OsrsError, ReadExt};
+    ///
+    /// let mut rdr = Cursor::new(vec![0, 1, 2]);
+    /// assert_eq!(rdr.read_bool_strict().unwrap(), false);
+    /// assert_eq!(rdr.read_bool_strict().unwrap(), true);
+    /// assert!(matches!(rdr.read_bool_strict(), Err(OsrsError::InvalidBool(2))));
+    ///
+    /// let mut rdr = Cursor::new(vec![2]);
+    /// assert_eq!(rdr.read_bool().unwrap(), true);
+    /// ```
+    #[inline]
+    fn read_bool_strict(&mut self) -> Result<bool> {
+        match self.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            b => Err(OsrsError::InvalidBool(b)),
+        }
+    }
+
+    /// Reads a tristate, where 0 is no, 1 is yes and 2 is inherit
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, Tristate};
+    ///
+    /// let mut rdr = Cursor::new(vec![0, 1, 2]);
+    /// assert_eq!(rdr.read_tristate().unwrap(), Tristate::No);
+    /// assert_eq!(rdr.read_tristate().unwrap(), Tristate::Yes);
+    /// assert_eq!(rdr.read_tristate().unwrap(), Tristate::Inherit);
+    /// ```
+    ///
+    /// Any other value is an error.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![3]);
+    /// assert!(rdr.read_tristate().is_err());
+    /// ```
+    #[inline]
+    fn read_tristate(&mut self) -> Result<Tristate> {
+        match self.read_u8()? {
+            0 => Ok(Tristate::No),
+            1 => Ok(Tristate::Yes),
+            2 => Ok(Tristate::Inherit),
+            n => Err(OsrsError::Other(format!(
+                "Failed reading tristate, value is {}",
+                n
+            ))),
+        }
+    }
+
     /// Reads an unsigned short as big endian
     ///
     /// # Examples
@@ -321,6 +572,24 @@ pub trait ReadExt: Read {
     /// let mut rdr = Cursor::new(vec![0xFF, 0xFF]);
     /// assert_eq!(rdr.read_u16_smart().unwrap(), 0x7FFF);
     /// ```
+    ///
+    /// Round-tripping the boundary values written by [`WriteExt::write_u16_smart`].
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// for n in [0, 127, 128, 32767] {
+    ///     wtr.write_u16_smart(n).unwrap();
+    /// }
+    /// assert_eq!(wtr, vec![0, 127, 0x80, 0x80, 0xFF, 0xFF]);
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in [0, 127, 128, 32767] {
+    ///     assert_eq!(rdr.read_u16_smart().unwrap(), n);
+    /// }
+    /// ```
     #[inline]
     fn read_u16_smart(&mut self) -> Result<u16> {
         let peek = self.read_u8()?;
@@ -534,32 +803,40 @@ pub trait ReadExt: Read {
 
     /// Reads a signed medium as big endian
     ///
+    /// Bit 23 is the sign bit and is extended into the returned value.
+    ///
     /// # Examples
     /// ```rust
     /// use std::io::Cursor;
     /// use osrs_bytes::ReadExt;
     ///
-    /// let mut rdr = Cursor::new(vec![255, 255, 255]);
-    /// assert_eq!(rdr.read_i24().unwrap(), 16777215);
+    /// let mut rdr = Cursor::new(vec![255, 255, 255, 128, 0, 0, 127, 255, 255]);
+    /// assert_eq!(rdr.read_i24().unwrap(), -1);
+    /// assert_eq!(rdr.read_i24().unwrap(), -8388608);
+    /// assert_eq!(rdr.read_i24().unwrap(), 8388607);
     /// ```
     #[inline]
     fn read_i24(&mut self) -> Result<i32> {
-        Ok(self.read_u24()? as i32)
+        Ok(((self.read_u24()? << 8) as i32) >> 8)
     }
 
     /// Reads a signed medium as little endian
     ///
+    /// Bit 23 is the sign bit and is extended into the returned value.
+    ///
     /// # Examples
     /// ```rust
     /// use std::io::Cursor;
     /// use osrs_bytes::ReadExt;
     ///
-    /// let mut rdr = Cursor::new(vec![255, 255, 255]);
-    /// assert_eq!(rdr.read_i24_le().unwrap(), 16777215);
+    /// let mut rdr = Cursor::new(vec![255, 255, 255, 0, 0, 128, 255, 255, 127]);
+    /// assert_eq!(rdr.read_i24_le().unwrap(), -1);
+    /// assert_eq!(rdr.read_i24_le().unwrap(), -8388608);
+    /// assert_eq!(rdr.read_i24_le().unwrap(), 8388607);
     /// ```
     #[inline]
     fn read_i24_le(&mut self) -> Result<i32> {
-        Ok(self.read_u24_le()? as i32)
+        Ok(((self.read_u24_le()? << 8) as i32) >> 8)
     }
 
     /// Reads an unsigned dword as big endian
@@ -645,6 +922,62 @@ pub trait ReadExt: Read {
         }
     }
 
+    /// Reads an unsigned dword as a big smart
+    ///
+    /// The top two bits of the first byte select the width: `00` is a single byte holding 0 to 0x3F, `01` is two bytes holding up to 0x3FFF and `10` is four bytes holding up to 0x3FFFFFFF. A first byte starting with `11` is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0x3F]);
+    /// assert_eq!(rdr.read_u32_big_smart().unwrap(), 0x3F);
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0x40, 0x40, 0x7F, 0xFF]);
+    /// assert_eq!(rdr.read_u32_big_smart().unwrap(), 0x40);
+    /// assert_eq!(rdr.read_u32_big_smart().unwrap(), 0x3FFF);
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0x80, 0x00, 0x40, 0x00, 0xBF, 0xFF, 0xFF, 0xFF]);
+    /// assert_eq!(rdr.read_u32_big_smart().unwrap(), 0x4000);
+    /// assert_eq!(rdr.read_u32_big_smart().unwrap(), 0x3FFFFFFF);
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0xC0, 0x00, 0x00, 0x00]);
+    /// assert!(rdr.read_u32_big_smart().is_err());
+    /// ```
+    #[inline]
+    fn read_u32_big_smart(&mut self) -> Result<u32> {
+        let peek = self.read_u8()?;
+        match peek >> 6 {
+            0 => Ok(peek as u32),
+            1 => Ok(((peek as u32 & 0x3F) << 8) | (self.read_u8()? as u32)),
+            2 => Ok(((peek as u32 & 0x3F) << 24)
+                | ((self.read_u8()? as u32) << 16)
+                | ((self.read_u8()? as u32) << 8)
+                | (self.read_u8()? as u32)),
+            _ => Err(OsrsError::Other(format!(
+                "Failed reading big smart, first byte is {}",
+                peek
+            ))),
+        }
+    }
+
     /// Reads an unsigned dword as little endian
     ///
     /// # Examples
@@ -665,6 +998,8 @@ pub trait ReadExt: Read {
 
     /// Reads an unsigned dword as middle endian
     ///
+    /// The high and then the low short, each as little endian, so 0xAABBCCDD is stored as `BB AA DD CC`.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -682,6 +1017,8 @@ pub trait ReadExt: Read {
 
     /// Reads an unsigned dword as inversed middle endian
     ///
+    /// The low and then the high short, each as big endian, so 0xAABBCCDD is stored as `CC DD AA BB`.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -855,7 +1192,7 @@ pub trait ReadExt: Read {
         Ok(u64::from_be_bytes(buf))
     }
 
-    /// Reads an signed dword as big endian
+    /// Reads an unsigned qword as little endian
     ///
     /// # Examples
     ///
@@ -863,15 +1200,19 @@ pub trait ReadExt: Read {
     /// use std::io::Cursor;
     /// use osrs_bytes::ReadExt;
     ///
-    /// let mut rdr = Cursor::new(vec![255, 84, 11, 99, 45, 12, 94, 36]);
-    /// assert_eq!(rdr.read_i64().unwrap(), -48401175408779740);
+    /// let mut rdr = Cursor::new(vec![36, 94, 12, 45, 99, 11, 84, 31]);
+    /// assert_eq!(rdr.read_u64_le().unwrap(), 2257441833804914212);
     /// ```
     #[inline]
-    fn read_i64(&mut self) -> Result<i64> {
-        Ok(self.read_u64()? as i64)
+    fn read_u64_le(&mut self) -> Result<u64> {
+        let mut buf = [0; 8];
+        self.read_exact(&mut buf)?;
+        Ok(u64::from_le_bytes(buf))
     }
 
-    /// Reads a CP1252 string
+    /// Reads an unsigned qword as middle endian
+    ///
+    /// The high dword comes first and the low dword second, each as little endian.
     ///
     /// # Examples
     ///
@@ -879,143 +1220,3325 @@ pub trait ReadExt: Read {
     /// use std::io::Cursor;
     /// use osrs_bytes::ReadExt;
     ///
-    /// let mut rdr = Cursor::new(vec![109, 121, 32, 116, 101, 115, 116, 0]);
-    /// assert_eq!(rdr.read_string_cp1252().unwrap(), "my test");
+    /// let mut rdr = Cursor::new(vec![4, 3, 2, 1, 8, 7, 6, 5]);
+    /// assert_eq!(rdr.read_u64_me().unwrap(), 0x0102030405060708);
     /// ```
     #[inline]
-    fn read_string_cp1252(&mut self) -> Result<String> {
-        let mut str = Vec::new();
-
-        while let Ok(x) = self.read_u8() {
-            if x != 0 {
-                str.push(x);
-            } else {
-                break;
-            }
-        }
-
-        let s = match std::str::from_utf8(&str) {
-            Ok(v) => v,
-            Err(e) => {
-                return Err(Error::new(
-                    ErrorKind::Other,
-                    format!("Invalid UTF-8 sequence: {}", e),
-                ))
-            }
-        };
-
-        Ok(s.to_owned())
+    fn read_u64_me(&mut self) -> Result<u64> {
+        Ok((self.read_u32_le()? as u64) << 32 | (self.read_u32_le()? as u64))
     }
-}
 
-impl<R: io::Read + ?Sized> ReadExt for R {}
-
-pub trait WriteExt: Write {
-    /// Writes an unsigned byte to the writer.
+    /// Reads an signed dword as big endian
     ///
-    /// Examples
+    /// # Examples
     ///
     /// ```rust
-    /// use osrs_bytes::WriteExt;
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
     ///
-    /// let mut wtr = Vec::new();
-    /// wtr.write_u8(42).unwrap();
-    /// assert_eq!(wtr[0], 42);
+    /// let mut rdr = Cursor::new(vec![255, 84, 11, 99, 45, 12, 94, 36]);
+    /// assert_eq!(rdr.read_i64().unwrap(), -48401175408779740);
     /// ```
     #[inline]
-    fn write_u8(&mut self, n: u8) -> Result<()> {
-        self.write_all(&[n])
+    fn read_i64(&mut self) -> Result<i64> {
+        Ok(self.read_u64()? as i64)
     }
 
-    /// Writes an unsigned byte to the writer.
+    /// Reads a signed qword as little endian
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use osrs_bytes::WriteExt;
-    ///
-    /// let mut wtr = Vec::new();
-    /// wtr.write_u8_add(42).unwrap();
-    /// assert_eq!(wtr[0], 170);
-    /// ```
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
     ///
-    /// ```rust
-    /// use osrs_bytes::WriteExt;
+    /// let mut rdr = Cursor::new(vec![8, 7, 6, 5, 4, 3, 2, 1]);
+    /// assert_eq!(rdr.read_i64_le().unwrap(), 0x0102030405060708);
     ///
-    /// let mut wtr = Vec::new();
-    /// wtr.write_u8_add(128).unwrap();
-    /// assert_eq!(wtr[0], 0);
+    /// let mut rdr = Cursor::new(vec![0, 0, 0, 0, 254, 255, 255, 255]);
+    /// assert_eq!(rdr.read_i64_le().unwrap(), -8589934592);
     /// ```
     #[inline]
-    fn write_u8_add(&mut self, n: u8) -> Result<()> {
-        self.write_u8(n.wrapping_add(128))
+    fn read_i64_le(&mut self) -> Result<i64> {
+        Ok(self.read_u64_le()? as i64)
     }
 
-    /// Writes an unsigned byte to the writer.
-    ///
-    /// # Examples
+    /// Reads a float as big endian
     ///
-    /// ```rust
-    /// use osrs_bytes::WriteExt;
+    /// The bit pattern is kept exactly, including for NaN and infinity.
     ///
-    /// let mut wtr = Vec::new();
-    /// wtr.write_u8_neg(42).unwrap();
-    /// assert_eq!(wtr[0], 214);
-    /// ```
+    /// # Examples
     ///
     /// ```rust
-    /// use osrs_bytes::WriteExt;
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
     ///
-    /// let mut wtr = Vec::new();
-    /// wtr.write_u8_neg(0).unwrap();
-    /// assert_eq!(wtr[0], 0);
+    /// let mut rdr = Cursor::new(vec![63, 128, 0, 0]);
+    /// assert_eq!(rdr.read_f32().unwrap(), 1.0);
     /// ```
     #[inline]
-    fn write_u8_neg(&mut self, n: u8) -> Result<()> {
-        self.write_u8(0u8.wrapping_sub(n))
+    fn read_f32(&mut self) -> Result<f32> {
+        let mut buf = [0; 4];
+        self.read_exact(&mut buf)?;
+        Ok(f32::from_be_bytes(buf))
     }
 
-    /// Writes an unsigned byte to the writer.
+    /// Reads a float as little endian
+    ///
+    /// The bit pattern is kept exactly, including for NaN and infinity.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use osrs_bytes::WriteExt;
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
     ///
-    /// let mut wtr = Vec::new();
-    /// wtr.write_u8_sub(42).unwrap();
-    /// assert_eq!(wtr[0], 86);
+    /// let mut rdr = Cursor::new(vec![0, 0, 128, 63]);
+    /// assert_eq!(rdr.read_f32_le().unwrap(), 1.0);
     /// ```
+    #[inline]
+    fn read_f32_le(&mut self) -> Result<f32> {
+        let mut buf = [0; 4];
+        self.read_exact(&mut buf)?;
+        Ok(f32::from_le_bytes(buf))
+    }
+
+    /// Reads a double as big endian
+    ///
+    /// The bit pattern is kept exactly, including for NaN and infinity.
+    ///
+    /// # Examples
     ///
     /// ```rust
-    /// use osrs_bytes::WriteExt;
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
     ///
-    /// let mut wtr = Vec::new();
-    /// wtr.write_u8_sub(128).unwrap();
-    /// assert_eq!(wtr[0], 0);
+    /// let mut rdr = Cursor::new(vec![192, 4, 0, 0, 0, 0, 0, 0]);
+    /// assert_eq!(rdr.read_f64().unwrap(), -2.5);
     /// ```
     #[inline]
-    fn write_u8_sub(&mut self, n: u8) -> Result<()> {
-        self.write_u8(128u8.wrapping_sub(n))
+    fn read_f64(&mut self) -> Result<f64> {
+        let mut buf = [0; 8];
+        self.read_exact(&mut buf)?;
+        Ok(f64::from_be_bytes(buf))
     }
 
-    /// Writes an signed byte to the writer.
+    /// Reads a double as little endian
     ///
-    /// Examples
+    /// The bit pattern is kept exactly, including for NaN and infinity.
+    ///
+    /// # Examples
     ///
     /// ```rust
-    /// use osrs_bytes::WriteExt;
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
     ///
-    /// let mut wtr = Vec::new();
-    /// wtr.write_i8(-67).unwrap();
-    /// assert_eq!(wtr[0] as i8, -67);
+    /// let mut rdr = Cursor::new(vec![0, 0, 0, 0, 0, 0, 4, 192]);
+    /// assert_eq!(rdr.read_f64_le().unwrap(), -2.5);
     /// ```
     #[inline]
-    fn write_i8(&mut self, n: i8) -> Result<()> {
-        self.write_u8(n as u8)
+    fn read_f64_le(&mut self) -> Result<f64> {
+        let mut buf = [0; 8];
+        self.read_exact(&mut buf)?;
+        Ok(f64::from_le_bytes(buf))
     }
 
-    /// Writes the number 128, subtracted by the signed byte to the writer.
+    /// Reads a CP1252 string
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![109, 121, 32, 116, 101, 115, 116, 0]);
+    /// assert_eq!(rdr.read_string_cp1252().unwrap(), "my test");
+    /// ```
+    ///
+    /// Bytes above 0x7F are decoded through the Windows-1252 table, not as UTF-8.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0x80, 0x35, 0, 0x69, 0x92, 0x6D, 0, 0x63, 0x61, 0x66, 0xE9, 0]);
+    /// assert_eq!(rdr.read_string_cp1252().unwrap(), "\u{20AC}5");
+    /// assert_eq!(rdr.read_string_cp1252().unwrap(), "i\u{2019}m");
+    /// assert_eq!(rdr.read_string_cp1252().unwrap(), "caf\u{E9}");
+    /// ```
+    ///
+    /// The five bytes CP1252 leaves undefined, 0x81, 0x8D, 0x8F, 0x90 and 0x9D, are an error.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0x61, 0x81, 0]);
+    /// assert!(rdr.read_string_cp1252().is_err());
+    /// ```
+    ///
+    /// The stream ending before the null terminator is an `UnexpectedEof` error.
+    ///
+    /// ```rust
+    /// use std::io::{Cursor, ErrorKind};
+    /// use osrs_bytes::{OsrsError, ReadExt};
+    ///
+    /// let mut rdr = Cursor::new(vec![104, 105]);
+    /// let err = rdr.read_string_cp1252().unwrap_err();
+    /// assert!(matches!(err, OsrsError::Io(e) if e.kind() == ErrorKind::UnexpectedEof));
+    /// ```
+    #[inline]
+    fn read_string_cp1252(&mut self) -> Result<String> {
+        decode_cp1252(&read_until_nul(self)?)
+    }
+
+    /// Reads a CP1252 string, replacing undefined bytes with U+FFFD
+    ///
+    /// Unlike [`ReadExt::read_string_cp1252`] this never fails on the bytes themselves, the same as `String::from_utf8_lossy`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0x61, 0x81, 0x80, 0xE9, 0]);
+    /// assert_eq!(rdr.read_string_cp1252_lossy().unwrap(), "a\u{FFFD}\u{20AC}\u{E9}");
+    /// ```
+    ///
+    /// It still fails on I/O, including the stream ending before the null terminator.
+    ///
+    /// ```rust
+    /// use std::io::{Cursor, ErrorKind};
+    /// use osrs_bytes::{OsrsError, ReadExt};
+    ///
+    /// let mut rdr = Cursor::new(vec![0x61, 0x81]);
+    /// let err = rdr.read_string_cp1252_lossy().unwrap_err();
+    /// assert!(matches!(err, OsrsError::Io(e) if e.kind() == ErrorKind::UnexpectedEof));
+    /// ```
+    #[inline]
+    fn read_string_cp1252_lossy(&mut self) -> Result<String> {
+        Ok(read_until_nul(self)?
+            .iter()
+            .map(|&b| decode_cp1252_char(b).unwrap_or('\u{FFFD}'))
+            .collect())
+    }
+
+    /// Reads a CP1252 string of exactly `len` bytes, without a terminator
+    ///
+    /// Null bytes within the string are kept as characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![104, 105, 0, 121, 111, 33]);
+    /// assert_eq!(rdr.read_string_cp1252_exact(5).unwrap(), "hi\0yo");
+    /// assert_eq!(rdr.read_u8().unwrap(), 33);
+    /// ```
+    #[inline]
+    fn read_string_cp1252_exact(&mut self, len: usize) -> Result<String> {
+        let mut buf = vec![0; len];
+        self.read_exact(&mut buf)?;
+        decode_cp1252(&buf)
+    }
+
+    /// Reads a versioned CP1252 string, a zero version byte followed by a null terminated string
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0, 104, 105, 0]);
+    /// assert_eq!(rdr.read_versioned_string().unwrap(), "hi");
+    /// ```
+    ///
+    /// A version byte other than zero is an error.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![104, 105, 0]);
+    /// assert!(rdr.read_versioned_string().is_err());
+    /// ```
+    #[inline]
+    fn read_versioned_string(&mut self) -> Result<String> {
+        let version = self.read_u8()?;
+        if version != 0 {
+            return Err(OsrsError::InvalidVersionByte(version));
+        }
+        self.read_string_cp1252()
+    }
+
+    /// Reads a CP1252 string prefixed with its length as an unsigned byte, without a terminator
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![2, 104, 105, 0]);
+    /// assert_eq!(rdr.read_string_prefixed().unwrap(), "hi");
+    /// assert_eq!(rdr.read_string_prefixed().unwrap(), "");
+    /// ```
+    #[inline]
+    fn read_string_prefixed(&mut self) -> Result<String> {
+        let len = self.read_u8()? as usize;
+        self.read_string_cp1252_exact(len)
+    }
+
+    /// Reads a CP1252 string prefixed with its length as an unsigned short, without a terminator
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0, 2, 104, 105]);
+    /// assert_eq!(rdr.read_string_prefixed_u16().unwrap(), "hi");
+    /// ```
+    #[inline]
+    fn read_string_prefixed_u16(&mut self) -> Result<String> {
+        let len = self.read_u16()? as usize;
+        self.read_string_cp1252_exact(len)
+    }
+
+    /// Reads a version byte and dispatches to the handler registered for it
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, Result, VersionHandler};
+    ///
+    /// fn v1(rdr: &mut Cursor<Vec<u8>>) -> Result<u32> {
+    ///     Ok(rdr.read_u16()? as u32)
+    /// }
+    ///
+    /// fn v2(rdr: &mut Cursor<Vec<u8>>) -> Result<u32> {
+    ///     rdr.read_u32()
+    /// }
+    ///
+    /// let handlers: [(u8, VersionHandler<_, u32>); 2] = [(1, v1), (2, v2)];
+    ///
+    /// let mut rdr = Cursor::new(vec![1, 0, 42]);
+    /// assert_eq!(rdr.read_versioned(&handlers).unwrap(), 42);
+    ///
+    /// let mut rdr = Cursor::new(vec![2, 0, 0, 1, 0]);
+    /// assert_eq!(rdr.read_versioned(&handlers).unwrap(), 256);
+    /// ```
+    ///
+    /// An unknown version is an error.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, Result, VersionHandler};
+    ///
+    /// fn v1(rdr: &mut Cursor<Vec<u8>>) -> Result<u8> {
+    ///     rdr.read_u8()
+    /// }
+    ///
+    /// let mut rdr = Cursor::new(vec![3, 0]);
+    /// let err = rdr.read_versioned(&[(1, v1 as VersionHandler<_, _>)]).unwrap_err();
+    /// assert_eq!(err.to_string(), "Failed reading versioned data, version is 3");
+    /// ```
+    #[inline]
+    fn read_versioned<T>(&mut self, handlers: &[(u8, VersionHandler<Self, T>)]) -> Result<T> {
+        let version = self.read_u8()?;
+        match handlers.iter().find(|(v, _)| *v == version) {
+            Some((_, handler)) => handler(self),
+            None => Err(OsrsError::InvalidVersionByte(version)),
+        }
+    }
+
+    /// Reads a ClientScript switch table, a u16 count followed by that many key and jump offset pairs
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![
+    ///     0, 2, // count
+    ///     0, 0, 0, 1, 0, 0, 0, 5, // 1 => 5
+    ///     255, 255, 255, 255, 0, 0, 0, 9, // -1 => 9
+    /// ]);
+    /// let table = rdr.read_switch_table().unwrap();
+    /// assert_eq!(table.len(), 2);
+    /// assert_eq!(table[&1], 5);
+    /// assert_eq!(table[&-1], 9);
+    /// ```
+    #[inline]
+    fn read_switch_table(&mut self) -> Result<HashMap<i32, i32>> {
+        let count = self.read_u16()? as usize;
+        let mut table = HashMap::with_capacity(count);
+        for _ in 0..count {
+            let key = self.read_i32()?;
+            let jump = self.read_i32()?;
+            table.insert(key, jump);
+        }
+        Ok(table)
+    }
+
+    /// Reads a loc shape and rotation packed into a single byte
+    ///
+    /// The shape is stored in the upper six bits and the rotation in the lower two bits, i.e. `shape << 2 | rotation`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0, 3, 41, 255]);
+    /// assert_eq!(rdr.read_loc_shape().unwrap(), (0, 0));
+    /// assert_eq!(rdr.read_loc_shape().unwrap(), (0, 3));
+    /// assert_eq!(rdr.read_loc_shape().unwrap(), (10, 1));
+    /// assert_eq!(rdr.read_loc_shape().unwrap(), (63, 3));
+    /// ```
+    #[inline]
+    fn read_loc_shape(&mut self) -> Result<(u8, u8)> {
+        let packed = self.read_u8()?;
+        Ok((packed >> 2, packed & 0x3))
+    }
+
+    /// Reads a magic number and checks that it matches the expected bytes
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![74, 65, 71, 1]);
+    /// rdr.read_magic(b"JAG").unwrap();
+    /// assert_eq!(rdr.read_u8().unwrap(), 1);
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![74, 65, 77]);
+    /// let err = rdr.read_magic(b"JAG").unwrap_err();
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "Failed reading magic, expected [74, 65, 71], got [74, 65, 77]"
+    /// );
+    /// ```
+    #[inline]
+    fn read_magic(&mut self, expected: &[u8]) -> Result<()> {
+        let mut buf = vec![0; expected.len()];
+        self.read_exact(&mut buf)?;
+        if buf != expected {
+            return Err(OsrsError::Other(format!(
+                "Failed reading magic, expected {:?}, got {:?}",
+                expected, buf
+            )));
+        }
+        Ok(())
+    }
+
+    /// Reads a ClientScript instruction, a u16 opcode followed by an operand of the type given by `operand_type` for that opcode
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{Operand, OperandType, ReadExt};
+    ///
+    /// let operand_type = |opcode| match opcode {
+    ///     3 => OperandType::Str,
+    ///     54 => OperandType::Long,
+    ///     _ => OperandType::Int,
+    /// };
+    ///
+    /// let mut rdr = Cursor::new(vec![
+    ///     0, 0, 0, 0, 0, 7, // opcode 0, int 7
+    ///     0, 3, 104, 105, 0, // opcode 3, string "hi"
+    ///     0, 54, 255, 255, 255, 255, 255, 255, 255, 254, // opcode 54, long -2
+    /// ]);
+    /// assert_eq!(rdr.read_cs2_instruction(operand_type).unwrap(), (0, Operand::Int(7)));
+    /// assert_eq!(rdr.read_cs2_instruction(operand_type).unwrap(), (3, Operand::Str("hi".to_owned())));
+    /// assert_eq!(rdr.read_cs2_instruction(operand_type).unwrap(), (54, Operand::Long(-2)));
+    /// ```
+    #[inline]
+    fn read_cs2_instruction<F: Fn(u16) -> OperandType>(
+        &mut self,
+        operand_type: F,
+    ) -> Result<(u16, Operand)> {
+        let opcode = self.read_u16()?;
+        let operand = match operand_type(opcode) {
+            OperandType::Int => Operand::Int(self.read_i32()?),
+            OperandType::Long => Operand::Long(self.read_i64()?),
+            OperandType::Str => Operand::Str(self.read_string_cp1252()?),
+        };
+        Ok((opcode, operand))
+    }
+
+    /// Reads a value of the type given by a param or enum type char
+    ///
+    /// Strings are CP1252 and every other type is a dword as big endian. The supported type chars are `i`, `s`, `o` and `I`, any other is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, TypedValue};
+    ///
+    /// let mut rdr = Cursor::new(vec![255, 255, 255, 254, 104, 105, 0, 0, 0, 4, 210, 0, 12, 0, 3]);
+    /// assert_eq!(rdr.read_typed_value('i').unwrap(), TypedValue::Int(-2));
+    /// assert_eq!(rdr.read_typed_value('s').unwrap(), TypedValue::Str("hi".to_owned()));
+    /// assert_eq!(rdr.read_typed_value('o').unwrap(), TypedValue::Obj(1234));
+    /// assert_eq!(rdr.read_typed_value('I').unwrap(), TypedValue::Component(786435));
+    /// assert!(rdr.read_typed_value('x').is_err());
+    /// ```
+    #[inline]
+    fn read_typed_value(&mut self, type_code: char) -> Result<TypedValue> {
+        Ok(match type_code {
+            'i' => TypedValue::Int(self.read_i32()?),
+            's' => TypedValue::Str(self.read_string_cp1252()?),
+            'o' => TypedValue::Obj(self.read_u32()?),
+            'I' => TypedValue::Component(self.read_u32()?),
+            _ => {
+                return Err(OsrsError::Other(format!(
+                    "Failed reading typed value, type is {:?}",
+                    type_code
+                )))
+            }
+        })
+    }
+
+    /// Reads exactly `len` bytes into a new vec
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![1, 2, 3, 4, 5, 6]);
+    /// assert_eq!(rdr.read_vec(4).unwrap(), vec![1, 2, 3, 4]);
+    /// assert_eq!(rdr.position(), 4);
+    /// ```
+    ///
+    /// Fewer than `len` bytes remaining is an `UnexpectedEof` error.
+    ///
+    /// ```rust
+    /// use std::io::{Cursor, ErrorKind};
+    /// use osrs_bytes::{OsrsError, ReadExt};
+    ///
+    /// let mut rdr = Cursor::new(vec![1, 2]);
+    /// let err = rdr.read_vec(3).unwrap_err();
+    /// assert!(matches!(err, OsrsError::Io(e) if e.kind() == ErrorKind::UnexpectedEof));
+    /// ```
+    #[inline]
+    fn read_vec(&mut self, len: usize) -> Result<Vec<u8>> {
+        let mut buf = vec![0; len];
+        self.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Reads exactly `N` bytes into an array, without allocating
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new((0..24).collect::<Vec<u8>>());
+    /// assert_eq!(rdr.read_array::<4>().unwrap(), [0, 1, 2, 3]);
+    /// assert_eq!(rdr.position(), 4);
+    ///
+    /// let key: [u8; 16] = rdr.read_array().unwrap();
+    /// assert_eq!(key, [4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19]);
+    /// assert_eq!(rdr.position(), 20);
+    /// assert!(rdr.read_array::<5>().is_err());
+    /// ```
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let mut buf = [0; N];
+        self.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+
+    /// Reads `len` bytes, XORing each with the key repeated cyclically
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let key = [0x12, 0x34, 0x56];
+    /// let payload = b"hello world";
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_bytes_xor(payload, &key).unwrap();
+    /// assert_eq!(wtr[0], b'h' ^ 0x12);
+    /// assert_eq!(wtr[3], b'l' ^ 0x12);
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// assert_eq!(rdr.read_bytes_xor(payload.len(), &key).unwrap(), payload);
+    /// ```
+    ///
+    /// An empty key is an error.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![1, 2, 3]);
+    /// assert!(rdr.read_bytes_xor(3, &[]).is_err());
+    /// ```
+    #[inline]
+    fn read_bytes_xor(&mut self, len: usize, key: &[u8]) -> Result<Vec<u8>> {
+        if key.is_empty() {
+            return Err(OsrsError::Other(
+                "Failed reading xor bytes, key is empty".to_owned(),
+            ));
+        }
+        let mut buf = vec![0; len];
+        self.read_exact(&mut buf)?;
+        for (b, k) in buf.iter_mut().zip(key.iter().cycle()) {
+            *b ^= k;
+        }
+        Ok(buf)
+    }
+
+    /// Reads an extended count
+    ///
+    /// The count is a single unsigned byte, unless that byte is 255. In that case the byte only signals that the real count follows as a big endian u32.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![42]);
+    /// assert_eq!(rdr.read_extended_count().unwrap(), 42);
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![255, 0, 1, 134, 160]);
+    /// assert_eq!(rdr.read_extended_count().unwrap(), 100000);
+    /// ```
+    #[inline]
+    fn read_extended_count(&mut self) -> Result<u32> {
+        match self.read_u8()? {
+            255 => self.read_u32(),
+            n => Ok(n as u32),
+        }
+    }
+
+    /// Reads a sub-tile coordinate pair
+    ///
+    /// Each coordinate is a signed short in fixed point with a scale of 128 units per tile.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![1, 128, 255, 192]);
+    /// assert_eq!(rdr.read_subtile_coord().unwrap(), (3.0, -0.5));
+    /// ```
+    #[inline]
+    fn read_subtile_coord(&mut self) -> Result<(f32, f32)> {
+        let x = self.read_i16()? as f32 / 128.0;
+        let y = self.read_i16()? as f32 / 128.0;
+        Ok((x, y))
+    }
+
+    /// Reads a length encoded as the given prefix
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{LengthPrefix, ReadExt};
+    ///
+    /// let mut rdr = Cursor::new(vec![5, 0, 5, 0x80, 0x80]);
+    /// assert_eq!(rdr.read_length(LengthPrefix::U8).unwrap(), 5);
+    /// assert_eq!(rdr.read_length(LengthPrefix::U16).unwrap(), 5);
+    /// assert_eq!(rdr.read_length(LengthPrefix::Smart).unwrap(), 128);
+    /// ```
+    #[inline]
+    fn read_length(&mut self, prefix: LengthPrefix) -> Result<usize> {
+        Ok(match prefix {
+            LengthPrefix::U8 => self.read_u8()? as usize,
+            LengthPrefix::U16 => self.read_u16()? as usize,
+            LengthPrefix::Smart => self.read_u16_smart()? as usize,
+        })
+    }
+
+    /// Reads a length prefixed vec, reading each element with `f`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{LengthPrefix, ReadExt};
+    ///
+    /// let mut rdr = Cursor::new(vec![2, 0, 1, 0, 2, 0]);
+    /// let v = rdr.read_prefixed_vec(LengthPrefix::Smart, |r| r.read_u16()).unwrap();
+    /// assert_eq!(v, vec![1, 2]);
+    /// let v = rdr.read_prefixed_vec(LengthPrefix::Smart, |r| r.read_u16()).unwrap();
+    /// assert!(v.is_empty());
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{LengthPrefix, ReadExt};
+    ///
+    /// let mut rdr = Cursor::new(vec![2, 104, 105, 0, 121, 111, 0, 0]);
+    /// let v = rdr.read_prefixed_vec(LengthPrefix::U8, |r| r.read_string_cp1252()).unwrap();
+    /// assert_eq!(v, vec!["hi", "yo"]);
+    /// let v = rdr.read_prefixed_vec(LengthPrefix::U8, |r| r.read_string_cp1252()).unwrap();
+    /// assert!(v.is_empty());
+    /// ```
+    #[inline]
+    fn read_prefixed_vec<T, F>(&mut self, prefix: LengthPrefix, mut f: F) -> Result<Vec<T>>
+    where
+        F: FnMut(&mut Self) -> Result<T>,
+    {
+        let len = self.read_length(prefix)?;
+        let mut v = Vec::with_capacity(len);
+        for _ in 0..len {
+            v.push(f(self)?);
+        }
+        Ok(v)
+    }
+
+    /// Reads a length prefixed table of CP1252 string key-value pairs
+    ///
+    /// Each entry is a key followed by its value, both null terminated. The entries are kept in the order they were read.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{LengthPrefix, ReadExt};
+    ///
+    /// let mut rdr = Cursor::new(vec![2, 104, 105, 0, 121, 111, 0, 97, 0, 98, 99, 0]);
+    /// assert_eq!(
+    ///     rdr.read_string_map(LengthPrefix::U8).unwrap(),
+    ///     vec![("hi".to_owned(), "yo".to_owned()), ("a".to_owned(), "bc".to_owned())]
+    /// );
+    /// ```
+    #[inline]
+    fn read_string_map(&mut self, prefix: LengthPrefix) -> Result<Vec<(String, String)>> {
+        self.read_prefixed_vec(prefix, |r| {
+            Ok((r.read_string_cp1252()?, r.read_string_cp1252()?))
+        })
+    }
+
+    /// Reads a CP1252 string into the option slot for an opcode range
+    ///
+    /// The slot is `opcode - base`, e.g. with a base of 30, opcode 32 is stored in `options[2]`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut options: [Option<String>; 5] = Default::default();
+    /// let mut rdr = Cursor::new(vec![84, 97, 107, 101, 0, 68, 114, 111, 112, 0]);
+    /// rdr.read_indexed_option(30, 30, &mut options).unwrap();
+    /// rdr.read_indexed_option(34, 30, &mut options).unwrap();
+    /// assert_eq!(options, [Some("Take".to_owned()), None, None, None, Some("Drop".to_owned())]);
+    /// ```
+    ///
+    /// An opcode outside of the range is an error.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut options: [Option<String>; 5] = Default::default();
+    /// let mut rdr = Cursor::new(vec![84, 97, 107, 101, 0]);
+    /// assert!(rdr.read_indexed_option(35, 30, &mut options).is_err());
+    /// ```
+    #[inline]
+    fn read_indexed_option(
+        &mut self,
+        opcode: u8,
+        base: u8,
+        options: &mut [Option<String>],
+    ) -> Result<()> {
+        let slot = opcode
+            .checked_sub(base)
+            .and_then(|i| options.get_mut(i as usize))
+            .ok_or(OsrsError::InvalidOpcode(opcode))?;
+        *slot = Some(self.read_string_cp1252()?);
+        Ok(())
+    }
+
+    /// Reads a varbit definition
+    ///
+    /// Opcode 1 holds the varp index as a u16 followed by the least and most significant bit, and opcode 0 ends the definition.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    /// use osrs_bytes::config::Varbit;
+    ///
+    /// let mut rdr = Cursor::new(vec![1, 1, 44, 4, 7, 0]);
+    /// assert_eq!(rdr.read_varbit().unwrap(), Varbit { index: 300, lsb: 4, msb: 7 });
+    /// ```
+    ///
+    /// Unknown opcodes are an error.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![2, 0]);
+    /// assert!(rdr.read_varbit().is_err());
+    /// ```
+    #[inline]
+    fn read_varbit(&mut self) -> Result<Varbit> {
+        let mut varbit = Varbit::default();
+        loop {
+            match self.read_u8()? {
+                0 => return Ok(varbit),
+                1 => {
+                    varbit.index = self.read_u16()?;
+                    varbit.lsb = self.read_u8()?;
+                    varbit.msb = self.read_u8()?;
+                }
+                opcode => return Err(OsrsError::InvalidOpcode(opcode)),
+            }
+        }
+    }
+
+    /// Reads an npc or object transform list
+    ///
+    /// The varbit and varp are u16s followed by a u8 count and `count + 1` u16 alternate ids. A value of 0xFFFF means none.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0, 12, 255, 255, 1, 255, 255, 4, 210]);
+    /// let transforms = rdr.read_transforms().unwrap();
+    /// assert_eq!(transforms.varbit, Some(12));
+    /// assert_eq!(transforms.varp, None);
+    /// assert_eq!(transforms.ids, vec![None, Some(1234)]);
+    /// ```
+    #[inline]
+    fn read_transforms(&mut self) -> Result<Transforms> {
+        let nullable = |id: u16| if id == 0xFFFF { None } else { Some(id) };
+        let varbit = nullable(self.read_u16()?);
+        let varp = nullable(self.read_u16()?);
+        let count = self.read_u8()? as usize;
+        let mut ids = Vec::with_capacity(count + 1);
+        for _ in 0..=count {
+            ids.push(nullable(self.read_u16()?));
+        }
+        Ok(Transforms { varbit, varp, ids })
+    }
+
+    /// Skips exactly `n` bytes
+    ///
+    /// This works on any reader, not just ones implementing `Seek`. Fewer than `n` bytes remaining is an `UnexpectedEof` error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::{Cursor, ErrorKind};
+    /// use osrs_bytes::{OsrsError, ReadExt};
+    ///
+    /// let mut rdr = Cursor::new(vec![1, 2, 3, 42]);
+    /// rdr.skip(3).unwrap();
+    /// assert_eq!(rdr.read_u8().unwrap(), 42);
+    /// let err = rdr.skip(1).unwrap_err();
+    /// assert!(matches!(err, OsrsError::Io(e) if e.kind() == ErrorKind::UnexpectedEof));
+    /// ```
+    #[inline]
+    fn skip(&mut self, n: usize) -> Result<()> {
+        let mut remaining = n;
+        let mut buf = [0; 64];
+        while remaining > 0 {
+            let n = remaining.min(buf.len());
+            self.read_exact(&mut buf[..n])?;
+            remaining -= n;
+        }
+        Ok(())
+    }
+
+    /// Skips the operands of an opcode, with the operand length given by `operand_len`
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let operand_len = |opcode| match opcode {
+    ///     7 => 3,
+    ///     _ => 0,
+    /// };
+    ///
+    /// let mut rdr = Cursor::new(vec![7, 1, 2, 3, 9, 42]);
+    /// let opcode = rdr.read_u8().unwrap();
+    /// rdr.skip_opcode_operands(opcode, operand_len).unwrap();
+    /// let opcode = rdr.read_u8().unwrap();
+    /// rdr.skip_opcode_operands(opcode, operand_len).unwrap();
+    /// assert_eq!(opcode, 9);
+    /// assert_eq!(rdr.read_u8().unwrap(), 42);
+    /// ```
+    #[inline]
+    fn skip_opcode_operands(
+        &mut self,
+        opcode: u8,
+        operand_len: impl Fn(u8) -> usize,
+    ) -> Result<()> {
+        self.skip(operand_len(opcode))
+    }
+
+    /// Reads a signed integer of 1 to 8 bytes, sign extending it into an i64
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{Endianness, ReadExt};
+    ///
+    /// let mut rdr = Cursor::new(vec![255, 255, 255, 0, 0, 127]);
+    /// assert_eq!(rdr.read_int_signed(3, Endianness::Big).unwrap(), -1);
+    /// assert_eq!(rdr.read_int_signed(3, Endianness::Big).unwrap(), 127);
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{Endianness, ReadExt};
+    ///
+    /// let mut rdr = Cursor::new(vec![255, 0, 0, 0, 0]);
+    /// assert_eq!(rdr.read_int_signed(5, Endianness::Big).unwrap(), -4294967296);
+    ///
+    /// let mut rdr = Cursor::new(vec![0, 0, 0, 0, 255]);
+    /// assert_eq!(rdr.read_int_signed(5, Endianness::Little).unwrap(), -4294967296);
+    /// ```
+    #[inline]
+    fn read_int_signed(&mut self, bytes: usize, endian: Endianness) -> Result<i64> {
+        if !(1..=8).contains(&bytes) {
+            return Err(OsrsError::Other(format!(
+                "Failed reading signed integer, width is {}",
+                bytes
+            )));
+        }
+        let mut buf = [0; 8];
+        self.read_exact(&mut buf[..bytes])?;
+        if endian == Endianness::Little {
+            buf[..bytes].reverse();
+        }
+        let shift = 64 - 8 * bytes as u32;
+        let n = buf[..bytes].iter().fold(0u64, |n, b| n << 8 | *b as u64);
+        Ok(((n << shift) as i64) >> shift)
+    }
+
+    /// Reads an object spawn
+    ///
+    /// The id is a smart, followed by the zone coordinates packed as `x << 4 | y` and the packed shape and rotation, see [`ReadExt::read_loc_shape`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ObjectSpawn, ReadExt};
+    ///
+    /// let mut rdr = Cursor::new(vec![0x85, 0xDC, 0x35, 41]);
+    /// assert_eq!(
+    ///     rdr.read_object_spawn().unwrap(),
+    ///     ObjectSpawn { id: 1500, x: 3, y: 5, shape: 10, rotation: 1 }
+    /// );
+    /// ```
+    #[inline]
+    fn read_object_spawn(&mut self) -> Result<ObjectSpawn> {
+        let id = self.read_u16_smart()?;
+        let coord = self.read_u8()?;
+        let (shape, rotation) = self.read_loc_shape()?;
+        Ok(ObjectSpawn {
+            id,
+            x: coord >> 4,
+            y: coord & 0xF,
+            shape,
+            rotation,
+        })
+    }
+
+    /// Returns a reader limited to the next `len` bytes
+    ///
+    /// The bytes the block reader did not read are skipped by [`BlockReader::finish`], or on a best effort basis when it is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0, 1, 2, 3, 4, 42]);
+    /// {
+    ///     let mut block = rdr.read_block(5);
+    ///     assert_eq!(block.read_u16().unwrap(), 1);
+    ///     assert_eq!(block.remaining(), 3);
+    /// }
+    /// assert_eq!(rdr.read_u8().unwrap(), 42);
+    /// ```
+    #[inline]
+    fn read_block(&mut self, len: usize) -> BlockReader<'_, Self> {
+        BlockReader::new(self, len)
+    }
+
+    /// Streams exactly `len` bytes into `digest` without keeping them
+    ///
+    /// The bytes are read in chunks through a [`DigestReader`], so a multi-megabyte file can be verified in one pass. Fewer than `len` bytes remaining is an `UnexpectedEof` error, after the bytes that were there have been added to the digest.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{Digest, ReadExt};
+    ///
+    /// struct Sum(u64);
+    ///
+    /// impl Digest for Sum {
+    ///     fn update(&mut self, data: &[u8]) {
+    ///         self.0 += data.iter().map(|&b| b as u64).sum::<u64>();
+    ///     }
+    /// }
+    ///
+    /// let data: Vec<u8> = (0..=255).cycle().take(100_000).collect();
+    /// let mut one_shot = Sum(0);
+    /// one_shot.update(&data[..99_999]);
+    ///
+    /// let mut rdr = Cursor::new(data);
+    /// let mut streamed = Sum(0);
+    /// rdr.read_and_digest(99_999, &mut streamed).unwrap();
+    /// assert_eq!(streamed.0, one_shot.0);
+    /// assert_eq!(rdr.read_u8().unwrap(), (99_999 % 256) as u8);
+    /// assert!(rdr.read_and_digest(1, &mut streamed).is_err());
+    /// ```
+    #[inline]
+    fn read_and_digest<D: Digest + ?Sized>(&mut self, len: usize, digest: &mut D) -> Result<()> {
+        let mut rdr = DigestReader::new(Read::take(&mut *self, len as u64), digest);
+        let copied = io::copy(&mut rdr, &mut io::sink())?;
+        if copied != len as u64 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!(
+                    "Failed reading digest, expected {} bytes, got {}",
+                    len, copied
+                ),
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// Reads a recolor array, a u8 count followed by that many source and destination HSL16 color pairs
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![2, 0x1A, 0x2B, 0x3C, 0x4D, 0, 10, 0, 20]);
+    /// assert_eq!(rdr.read_recolors().unwrap(), vec![(0x1A2B, 0x3C4D), (10, 20)]);
+    /// ```
+    #[inline]
+    fn read_recolors(&mut self) -> Result<Vec<(u16, u16)>> {
+        let count = self.read_u8()? as usize;
+        let mut pairs = Vec::with_capacity(count);
+        for _ in 0..count {
+            pairs.push((self.read_u16()?, self.read_u16()?));
+        }
+        Ok(pairs)
+    }
+
+    /// Reads a retexture array, a u8 count followed by that many source and destination texture id pairs
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![1, 0, 40, 0, 41]);
+    /// assert_eq!(rdr.read_retextures().unwrap(), vec![(40, 41)]);
+    /// ```
+    #[inline]
+    fn read_retextures(&mut self) -> Result<Vec<(u16, u16)>> {
+        self.read_recolors()
+    }
+
+    /// Reads an unsigned dword with a custom byte order
+    ///
+    /// `order[i]` is the position of the `i`th byte read in the big endian value, so `[0, 1, 2, 3]` is big endian and `[3, 2, 1, 0]` is little endian.
+    ///
+    /// # Examples
+    ///
+    /// Middle endian, the same as [`ReadExt::read_u32_me`]:
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![1, 5, 9, 49]);
+    /// assert_eq!(rdr.read_u32_permuted([1, 0, 3, 2]).unwrap(), 83964169);
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0x11, 0x22, 0x33, 0x44]);
+    /// assert_eq!(rdr.read_u32_permuted([2, 0, 3, 1]).unwrap(), 0x22441133);
+    /// ```
+    ///
+    /// An order that is not a permutation of 0 to 3 is an error.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0x11, 0x22, 0x33, 0x44]);
+    /// assert!(rdr.read_u32_permuted([0, 0, 1, 2]).is_err());
+    /// ```
+    #[inline]
+    fn read_u32_permuted(&mut self, order: [usize; 4]) -> Result<u32> {
+        let mut seen = [false; 4];
+        for &pos in &order {
+            if pos > 3 || seen[pos] {
+                return Err(OsrsError::Other(format!(
+                    "Failed reading permuted dword, order is {:?}",
+                    order
+                )));
+            }
+            seen[pos] = true;
+        }
+        let mut src = [0; 4];
+        self.read_exact(&mut src)?;
+        let mut buf = [0; 4];
+        for (b, &pos) in src.iter().zip(&order) {
+            buf[pos] = *b;
+        }
+        Ok(u32::from_be_bytes(buf))
+    }
+
+    /// Reads `count` bools packed into bits, most significant bit first
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0b1010_0001, 0b0100_0000, 42]);
+    /// assert_eq!(
+    ///     rdr.read_bool_array(10).unwrap(),
+    ///     vec![true, false, true, false, false, false, false, true, false, true]
+    /// );
+    /// assert_eq!(rdr.read_u8().unwrap(), 42);
+    /// ```
+    #[inline]
+    fn read_bool_array(&mut self, count: usize) -> Result<Vec<bool>> {
+        let mut buf = vec![0; count.div_ceil(8)];
+        self.read_exact(&mut buf)?;
+        Ok((0..count)
+            .map(|i| buf[i / 8] & (0x80 >> (i % 8)) != 0)
+            .collect())
+    }
+
+    /// Reads `count` floats as big endian with a single read
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![63, 128, 0, 0, 192, 32, 0, 0]);
+    /// assert_eq!(rdr.read_f32_array(2).unwrap(), vec![1.0, -2.5]);
+    /// ```
+    ///
+    /// The buffer only grows as bytes arrive, so a huge `count` from untrusted data fails once the stream ends instead of allocating up front.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![63, 128, 0, 0, 192, 32, 0, 0]);
+    /// assert!(rdr.read_f32_array(1 << 40).is_err());
+    /// assert!(rdr.read_f32_array(usize::MAX).is_err());
+    /// ```
+    #[inline]
+    fn read_f32_array(&mut self, count: usize) -> Result<Vec<f32>> {
+        let buf = read_bounded(self, count, 4)?;
+        Ok(buf
+            .chunks_exact(4)
+            .map(|b| f32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+            .collect())
+    }
+
+    /// Reads `count` floats as little endian with a single read
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0, 0, 128, 63, 0, 0, 32, 192]);
+    /// assert_eq!(rdr.read_f32_array_le(2).unwrap(), vec![1.0, -2.5]);
+    /// ```
+    ///
+    /// The buffer only grows as bytes arrive, so a huge `count` from untrusted data fails once the stream ends instead of allocating up front.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0, 0, 128, 63, 0, 0, 32, 192]);
+    /// assert!(rdr.read_f32_array_le(1 << 40).is_err());
+    /// assert!(rdr.read_f32_array_le(usize::MAX).is_err());
+    /// ```
+    #[inline]
+    fn read_f32_array_le(&mut self, count: usize) -> Result<Vec<f32>> {
+        let buf = read_bounded(self, count, 4)?;
+        Ok(buf
+            .chunks_exact(4)
+            .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+            .collect())
+    }
+
+    /// Reads `count` HSL16 model face colors as big endian with a single read
+    ///
+    /// See [`color::hsl_array_to_rgb`] to convert them to RGB.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{color, ReadExt};
+    ///
+    /// let mut rdr = Cursor::new(vec![0x1A, 0x2B, 0, 127, 0x3C, 0x4D]);
+    /// let colors = rdr.read_face_colors(3).unwrap();
+    /// assert_eq!(colors, vec![0x1A2B, 127, 0x3C4D]);
+    /// assert_eq!(color::hsl_to_rgb(colors[0]), 0x866025);
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0x1A, 0x2B, 0]);
+    /// assert!(rdr.read_face_colors(2).is_err());
+    /// assert!(rdr.read_face_colors(usize::MAX).is_err());
+    /// ```
+    #[inline]
+    fn read_face_colors(&mut self, count: usize) -> Result<Vec<u16>> {
+        let buf = read_bounded(self, count, 2)?;
+        Ok(buf
+            .chunks_exact(2)
+            .map(|b| u16::from_be_bytes([b[0], b[1]]))
+            .collect())
+    }
+
+    /// Reads the u16 item link for an item definition opcode into the matching field
+    ///
+    /// Opcode 97 is the note link, 98 the note template, 148 the placeholder link and 149 the placeholder template.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    /// use osrs_bytes::config::ItemLinks;
+    ///
+    /// let mut links = ItemLinks::default();
+    /// let mut rdr = Cursor::new(vec![4, 210, 3, 87, 55, 16]);
+    /// rdr.read_item_links(97, &mut links).unwrap();
+    /// rdr.read_item_links(98, &mut links).unwrap();
+    /// rdr.read_item_links(148, &mut links).unwrap();
+    /// assert_eq!(links.note_link, Some(1234));
+    /// assert_eq!(links.note_template, Some(855));
+    /// assert_eq!(links.placeholder_link, Some(14096));
+    /// assert_eq!(links.placeholder_template, None);
+    /// ```
+    ///
+    /// Any other opcode is an error.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    /// use osrs_bytes::config::ItemLinks;
+    ///
+    /// let mut rdr = Cursor::new(vec![0, 1]);
+    /// assert!(rdr.read_item_links(1, &mut ItemLinks::default()).is_err());
+    /// ```
+    #[inline]
+    fn read_item_links(&mut self, opcode: u8, links: &mut ItemLinks) -> Result<()> {
+        let field = match opcode {
+            97 => &mut links.note_link,
+            98 => &mut links.note_template,
+            148 => &mut links.placeholder_link,
+            149 => &mut links.placeholder_template,
+            _ => return Err(OsrsError::InvalidOpcode(opcode)),
+        };
+        *field = Some(self.read_u16()?);
+        Ok(())
+    }
+
+    /// Reads the equipped model for an item definition opcode into the matching field
+    ///
+    /// Opcodes 23 and 25 are followed by a signed byte offset for the male and female models respectively.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    /// use osrs_bytes::config::ItemEquipModels;
+    ///
+    /// let mut models = ItemEquipModels::default();
+    /// let mut rdr = Cursor::new(vec![
+    ///     0x0C, 0x8E, 6, // 23
+    ///     0x0C, 0x8F, // 24
+    ///     0x13, 0x04, 0xFA, // 25
+    ///     0x13, 0x05, // 26
+    ///     0x00, 0x7B, // 90
+    ///     0x01, 0xC8, // 91
+    /// ]);
+    /// for opcode in [23, 24, 25, 26, 90, 91] {
+    ///     rdr.read_item_equip_models(opcode, &mut models).unwrap();
+    /// }
+    /// assert_eq!(models.male_model0, Some(3214));
+    /// assert_eq!(models.male_offset, 6);
+    /// assert_eq!(models.male_model1, Some(3215));
+    /// assert_eq!(models.female_model0, Some(4868));
+    /// assert_eq!(models.female_offset, -6);
+    /// assert_eq!(models.female_model1, Some(4869));
+    /// assert_eq!(models.male_head_model0, Some(123));
+    /// assert_eq!(models.female_head_model0, Some(456));
+    /// assert_eq!(models.male_model2, None);
+    /// ```
+    ///
+    /// Any other opcode is an error.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    /// use osrs_bytes::config::ItemEquipModels;
+    ///
+    /// let mut rdr = Cursor::new(vec![0, 1]);
+    /// assert!(rdr.read_item_equip_models(97, &mut ItemEquipModels::default()).is_err());
+    /// ```
+    #[inline]
+    fn read_item_equip_models(&mut self, opcode: u8, models: &mut ItemEquipModels) -> Result<()> {
+        let field = match opcode {
+            23 | 25 => {
+                let model = self.read_u16()?;
+                let offset = self.read_i8()?;
+                if opcode == 23 {
+                    models.male_model0 = Some(model);
+                    models.male_offset = offset;
+                } else {
+                    models.female_model0 = Some(model);
+                    models.female_offset = offset;
+                }
+                return Ok(());
+            }
+            24 => &mut models.male_model1,
+            26 => &mut models.female_model1,
+            78 => &mut models.male_model2,
+            79 => &mut models.female_model2,
+            90 => &mut models.male_head_model0,
+            91 => &mut models.female_head_model0,
+            92 => &mut models.male_head_model1,
+            93 => &mut models.female_head_model1,
+            _ => return Err(OsrsError::InvalidOpcode(opcode)),
+        };
+        *field = Some(self.read_u16()?);
+        Ok(())
+    }
+
+    /// Reads a 4-byte sentinel and returns the byte order it was written in
+    ///
+    /// The sentinel must read differently in both byte orders, otherwise the order cannot be told apart and this is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{Endianness, ReadExt};
+    ///
+    /// let mut rdr = Cursor::new(vec![0x12, 0x34, 0x56, 0x78]);
+    /// assert_eq!(rdr.read_endianness(0x12345678).unwrap(), Endianness::Big);
+    ///
+    /// let mut rdr = Cursor::new(vec![0x78, 0x56, 0x34, 0x12]);
+    /// assert_eq!(rdr.read_endianness(0x12345678).unwrap(), Endianness::Little);
+    /// ```
+    ///
+    /// An unrecognized sentinel is an error.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0x12, 0x34, 0x56, 0x79]);
+    /// assert!(rdr.read_endianness(0x12345678).is_err());
+    /// ```
+    #[inline]
+    fn read_endianness(&mut self, sentinel: u32) -> Result<Endianness> {
+        if sentinel == sentinel.swap_bytes() {
+            return Err(OsrsError::Other(format!(
+                "Failed reading endianness, sentinel {:#010x} is the same in both byte orders",
+                sentinel
+            )));
+        }
+        let marker = self.read_u32()?;
+        if marker == sentinel {
+            Ok(Endianness::Big)
+        } else if marker == sentinel.swap_bytes() {
+            Ok(Endianness::Little)
+        } else {
+            Err(OsrsError::Other(format!(
+                "Failed reading endianness, marker is {:#010x}",
+                marker
+            )))
+        }
+    }
+
+    /// Reads an item container of `size` slots
+    ///
+    /// Each slot is the item id plus one as a smart, where 0 is an empty slot, followed by the count as an extended count, see [`ReadExt::read_extended_count`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![
+    ///     0x84, 0xE3, 1, // 1250 x 1
+    ///     0, 0, // empty
+    ///     0x8F, 0xE4, 255, 0, 0, 3, 232, // 4067 x 1000
+    /// ]);
+    /// assert_eq!(
+    ///     rdr.read_item_container(3).unwrap(),
+    ///     vec![Some((1250, 1)), None, Some((4067, 1000))]
+    /// );
+    /// ```
+    #[inline]
+    fn read_item_container(&mut self, size: usize) -> Result<Vec<Option<(u32, u32)>>> {
+        let mut items = Vec::with_capacity(size);
+        for _ in 0..size {
+            let id = self.read_u16_smart()? as u32;
+            let count = self.read_extended_count()?;
+            items.push(id.checked_sub(1).map(|id| (id, count)));
+        }
+        Ok(items)
+    }
+
+    /// Reads a CP1252 string and interns it, so repeated strings share a single copy
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, StringInterner};
+    ///
+    /// let mut interner = StringInterner::new();
+    /// let mut rdr = Cursor::new(vec![84, 97, 107, 101, 0, 84, 97, 107, 101, 0, 85, 115, 101, 0]);
+    /// let a = rdr.read_string_cp1252_interned(&mut interner).unwrap();
+    /// let b = rdr.read_string_cp1252_interned(&mut interner).unwrap();
+    /// let c = rdr.read_string_cp1252_interned(&mut interner).unwrap();
+    /// assert_eq!(a, b);
+    /// assert_ne!(a, c);
+    /// assert_eq!(interner.resolve(c), "Use");
+    /// ```
+    #[inline]
+    fn read_string_cp1252_interned(
+        &mut self,
+        interner: &mut StringInterner,
+    ) -> Result<InternedString> {
+        Ok(interner.intern(self.read_string_cp1252()?))
+    }
+
+    /// Reads a delta encoded array of `count` values
+    ///
+    /// The first value is an unsigned short, and every following value is the previous value plus a signed smart delta, see [`ReadExt::read_i16_smart`]. A value that drops below zero or rises above 65535 is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![3, 232, 0xC0, 0xC8, 0x3B]);
+    /// assert_eq!(rdr.read_delta_u16_array(3).unwrap(), vec![1000, 1200, 1195]);
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![255, 255, 0xC0, 0x41]);
+    /// assert!(rdr.read_delta_u16_array(2).is_err());
+    ///
+    /// let mut rdr = Cursor::new(vec![0, 0, 0x3F]);
+    /// assert!(rdr.read_delta_u16_array(2).is_err());
+    /// ```
+    #[inline]
+    fn read_delta_u16_array(&mut self, count: usize) -> Result<Vec<u32>> {
+        let mut values = Vec::with_capacity(count);
+        if count == 0 {
+            return Ok(values);
+        }
+        let mut value = self.read_u16()? as u32;
+        values.push(value);
+        for _ in 1..count {
+            let delta = self.read_i16_smart()?;
+            value = value
+                .checked_add_signed(delta as i32)
+                .filter(|v| *v <= u16::MAX as u32)
+                .ok_or_else(|| {
+                    OsrsError::Other(format!(
+                        "Failed reading delta array, {} plus delta {} is out of range",
+                        value, delta
+                    ))
+                })?;
+            values.push(value);
+        }
+        Ok(values)
+    }
+
+    /// Reads `count` smart increments and returns their running totals
+    ///
+    /// Each value is the previous value plus an unsigned smart, see [`ReadExt::read_u16_smart`], starting from zero. A total that overflows u16 is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![5, 3, 0, 10]);
+    /// assert_eq!(rdr.read_u16_smart_increments(4).unwrap(), vec![5, 8, 8, 18]);
+    ///
+    /// let mut rdr = Cursor::new(vec![0xFF, 0xFF, 0xFF, 0xFF, 0x80, 0x02]);
+    /// assert!(rdr.read_u16_smart_increments(3).is_err());
+    /// ```
+    #[inline]
+    fn read_u16_smart_increments(&mut self, count: usize) -> Result<Vec<u16>> {
+        let mut values = Vec::with_capacity(count);
+        let mut value: u16 = 0;
+        for _ in 0..count {
+            let delta = self.read_u16_smart()?;
+            value = value.checked_add(delta).ok_or_else(|| {
+                OsrsError::Other(format!(
+                    "Failed reading smart increments, {} plus {} overflows",
+                    value, delta
+                ))
+            })?;
+            values.push(value);
+        }
+        Ok(values)
+    }
+
+    /// Reads the file size table of a multi-file archive
+    ///
+    /// The table holds a signed dword delta for every file in every chunk. Within a chunk the deltas are accumulated, so each file's size in that chunk is the sum of the deltas up to and including it. The sizes are returned indexed by chunk, then by file. A size that drops below zero is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0, 0, 0, 10, 0, 0, 0, 5, 255, 255, 255, 253]);
+    /// assert_eq!(rdr.read_archive_sizes(3, 1).unwrap(), vec![vec![10, 15, 12]]);
+    /// ```
+    ///
+    /// The running size restarts at zero for every chunk.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0, 0, 0, 4, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0, 1]);
+    /// assert_eq!(rdr.read_archive_sizes(2, 2).unwrap(), vec![vec![4, 6], vec![1, 2]]);
+    /// ```
+    #[inline]
+    fn read_archive_sizes(
+        &mut self,
+        file_count: usize,
+        chunk_count: usize,
+    ) -> Result<Vec<Vec<u32>>> {
+        let mut sizes = Vec::with_capacity(chunk_count);
+        for _ in 0..chunk_count {
+            let mut chunk = Vec::with_capacity(file_count);
+            let mut size: u32 = 0;
+            for _ in 0..file_count {
+                let delta = self.read_i32()?;
+                size = size.checked_add_signed(delta).ok_or_else(|| {
+                    OsrsError::Other(format!(
+                        "Failed reading archive sizes, {} plus delta {} is out of range",
+                        size, delta
+                    ))
+                })?;
+                chunk.push(size);
+            }
+            sizes.push(chunk);
+        }
+        Ok(sizes)
+    }
+
+    /// Reads bytes reversed with add into the buffer
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_bytes_reversed_add(&[1, 2, 3]).unwrap();
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// let mut buf = [0; 3];
+    /// rdr.read_bytes_reversed_add(&mut buf).unwrap();
+    /// assert_eq!(buf, [1, 2, 3]);
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![131, 130]);
+    /// let mut buf = [0; 3];
+    /// assert!(rdr.read_bytes_reversed_add(&mut buf).is_err());
+    /// ```
+    #[inline]
+    fn read_bytes_reversed_add(&mut self, buf: &mut [u8]) -> Result<()> {
+        self.read_exact(buf)?;
+        buf.reverse();
+        for b in buf.iter_mut() {
+            *b = b.wrapping_sub(128);
+        }
+        Ok(())
+    }
+
+    /// Reads bytes with add into the buffer
+    ///
+    /// Each byte has 128 subtracted from it, the same as [`ReadExt::read_u8_add`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![129, 130, 131, 0]);
+    /// let mut buf = [0; 3];
+    /// rdr.read_bytes_add(&mut buf).unwrap();
+    /// assert_eq!(buf, [1, 2, 3]);
+    /// assert_eq!(rdr.read_u8_add().unwrap(), 128);
+    /// ```
+    #[inline]
+    fn read_bytes_add(&mut self, buf: &mut [u8]) -> Result<()> {
+        self.read_exact(buf)?;
+        for b in buf.iter_mut() {
+            *b = b.wrapping_sub(128);
+        }
+        Ok(())
+    }
+
+    /// Reads a sound effect envelope
+    ///
+    /// The form is a u8, followed by the start and end as i32s and a u8 segment count. Each segment is a u16 duration and a u16 phase.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![
+    ///     1, // form
+    ///     0, 0, 0, 100, // start
+    ///     255, 255, 255, 156, // end
+    ///     2, // segments
+    ///     0, 0, 0, 0, 255, 255, 255, 255,
+    /// ]);
+    /// let envelope = rdr.read_envelope().unwrap();
+    /// assert_eq!(envelope.form, 1);
+    /// assert_eq!(envelope.start, 100);
+    /// assert_eq!(envelope.end, -100);
+    /// assert_eq!(envelope.segments, vec![(0, 0), (65535, 65535)]);
+    /// ```
+    #[inline]
+    fn read_envelope(&mut self) -> Result<Envelope> {
+        let form = self.read_u8()?;
+        let start = self.read_i32()?;
+        let end = self.read_i32()?;
+        let count = self.read_u8()? as usize;
+        let mut segments = Vec::with_capacity(count);
+        for _ in 0..count {
+            segments.push((self.read_u16()?, self.read_u16()?));
+        }
+        Ok(Envelope {
+            form,
+            start,
+            end,
+            segments,
+        })
+    }
+
+    /// Reads an enum definition, up to and including the terminating zero opcode
+    ///
+    /// Opcodes 1 and 2 are the key and value type chars, 3 and 4 the default string and int, and 5 and 6 a u16 count of int keys each followed by a string or int value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![
+    ///     1, b'i', 2, b's', 3, b'?', 0,
+    ///     5, 0, 2, 0, 0, 0, 1, b'o', b'n', b'e', 0, 0, 0, 0, 2, b't', b'w', b'o', 0,
+    ///     0,
+    /// ]);
+    /// let config = rdr.read_enum_config().unwrap();
+    /// assert_eq!(config.key_type, 'i');
+    /// assert_eq!(config.value_type, 's');
+    /// assert_eq!(config.default_string, "?");
+    /// assert_eq!(config.string_values[&1], "one");
+    /// assert_eq!(config.string_values[&2], "two");
+    /// assert!(config.int_values.is_empty());
+    /// ```
+    ///
+    /// Any other opcode is an error.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![7, 0]);
+    /// assert!(rdr.read_enum_config().is_err());
+    /// ```
+    #[inline]
+    fn read_enum_config(&mut self) -> Result<EnumConfig> {
+        let mut config = EnumConfig::default();
+        loop {
+            match self.read_u8()? {
+                0 => return Ok(config),
+                1 => config.key_type = self.read_u8()? as char,
+                2 => config.value_type = self.read_u8()? as char,
+                3 => config.default_string = self.read_string_cp1252()?,
+                4 => config.default_int = self.read_i32()?,
+                5 => {
+                    for _ in 0..self.read_u16()? {
+                        let key = self.read_i32()?;
+                        config.string_values.insert(key, self.read_string_cp1252()?);
+                    }
+                }
+                6 => {
+                    for _ in 0..self.read_u16()? {
+                        let key = self.read_i32()?;
+                        config.int_values.insert(key, self.read_i32()?);
+                    }
+                }
+                opcode => return Err(OsrsError::InvalidOpcode(opcode)),
+            }
+        }
+    }
+
+    /// Reads a spotanim definition, up to and including the terminating zero opcode
+    ///
+    /// Opcodes 1 and 2 are the model and animation, 4 to 6 the horizontal scale, vertical scale and rotation as u16s, 7 and 8 the ambient lighting and contrast as u8s, and 40 and 41 the recolors and retextures, see [`ReadExt::read_recolors`]. The scales default to 128.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![
+    ///     1, 0x3A, 0x98, 2, 0x02, 0xEE, 5, 0, 64, 8, 30,
+    ///     40, 1, 0x1A, 0x2B, 0x3C, 0x4D,
+    ///     0,
+    /// ]);
+    /// let spotanim = rdr.read_spotanim().unwrap();
+    /// assert_eq!(spotanim.model, 15000);
+    /// assert_eq!(spotanim.animation, Some(750));
+    /// assert_eq!(spotanim.resize_x, 128);
+    /// assert_eq!(spotanim.resize_y, 64);
+    /// assert_eq!(spotanim.contrast, 30);
+    /// assert_eq!(spotanim.recolors, vec![(0x1A2B, 0x3C4D)]);
+    /// assert!(spotanim.retextures.is_empty());
+    /// ```
+    ///
+    /// Any other opcode is an error.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![3, 0]);
+    /// assert!(rdr.read_spotanim().is_err());
+    /// ```
+    #[inline]
+    fn read_spotanim(&mut self) -> Result<SpotAnim> {
+        let mut spotanim = SpotAnim::default();
+        loop {
+            match self.read_u8()? {
+                0 => return Ok(spotanim),
+                1 => spotanim.model = self.read_u16()?,
+                2 => spotanim.animation = Some(self.read_u16()?),
+                4 => spotanim.resize_x = self.read_u16()?,
+                5 => spotanim.resize_y = self.read_u16()?,
+                6 => spotanim.rotation = self.read_u16()?,
+                7 => spotanim.ambient = self.read_u8()?,
+                8 => spotanim.contrast = self.read_u8()?,
+                40 => spotanim.recolors = self.read_recolors()?,
+                41 => spotanim.retextures = self.read_retextures()?,
+                opcode => return Err(OsrsError::InvalidOpcode(opcode)),
+            }
+        }
+    }
+
+    /// Reads a health bar definition, up to and including the terminating zero opcode
+    ///
+    /// The sprites of opcodes 7 and 8 are read as [`ReadExt::read_u32_smart`], where 32767 means none. Opcodes 1 and 6 hold a u16 and a u8 the client ignores, and they are skipped the same way.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![
+    ///     1, 0, 9, 5, 0, 40, 7, 0x0B, 0xB8, 8, 0x7F, 0xFF, 11, 1, 44, 14, 60, 15, 2,
+    ///     0,
+    /// ]);
+    /// let hitbar = rdr.read_hitbar().unwrap();
+    /// assert_eq!(hitbar.fade_duration, 40);
+    /// assert_eq!(hitbar.front_sprite, Some(3000));
+    /// assert_eq!(hitbar.back_sprite, None);
+    /// assert_eq!(hitbar.duration, Some(300));
+    /// assert_eq!(hitbar.width, 60);
+    /// assert_eq!(hitbar.padding, 2);
+    /// assert_eq!(hitbar.int1, 255);
+    /// ```
+    ///
+    /// Any other opcode is an error.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![9, 0]);
+    /// assert!(rdr.read_hitbar().is_err());
+    /// ```
+    #[inline]
+    fn read_hitbar(&mut self) -> Result<HitBar> {
+        let nullable = |sprite: u32| if sprite == 32767 { None } else { Some(sprite) };
+        let mut hitbar = HitBar::default();
+        loop {
+            match self.read_u8()? {
+                0 => return Ok(hitbar),
+                1 => {
+                    self.read_u16()?;
+                }
+                2 => hitbar.int1 = self.read_u8()?,
+                3 => hitbar.int2 = self.read_u8()?,
+                4 => hitbar.duration = Some(0),
+                5 => hitbar.fade_duration = self.read_u16()?,
+                6 => {
+                    self.read_u8()?;
+                }
+                7 => hitbar.front_sprite = nullable(self.read_u32_smart()?),
+                8 => hitbar.back_sprite = nullable(self.read_u32_smart()?),
+                11 => hitbar.duration = Some(self.read_u16()?),
+                14 => hitbar.width = self.read_u8()?,
+                15 => hitbar.padding = self.read_u8()?,
+                opcode => return Err(OsrsError::InvalidOpcode(opcode)),
+            }
+        }
+    }
+
+    /// Reads an XTEA enciphered block of two dwords as big endian and deciphers it
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0xDE, 0xE9, 0xD4, 0xD8, 0xF7, 0x13, 0x1E, 0xD9]);
+    /// assert_eq!(rdr.read_xtea_block(&[0; 4]).unwrap(), [0, 0]);
+    /// ```
+    #[inline]
+    fn read_xtea_block(&mut self, key: &[u32; 4]) -> Result<[u32; 2]> {
+        let block = [self.read_u32()?, self.read_u32()?];
+        Ok(xtea::decipher(block, key))
+    }
+
+    /// Reads a packet opcode, subtracting the next offset of the ISAAC generator
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{Isaac, ReadExt, WriteExt};
+    ///
+    /// let seed = [0x11223344, 0x55667788, 0x99AABBCC, 0xDDEEFF00];
+    /// let mut encoder = Isaac::new(seed);
+    /// let mut wtr = Vec::new();
+    /// for opcode in [0, 35, 255] {
+    ///     wtr.write_packet_opcode(opcode, &mut encoder).unwrap();
+    /// }
+    ///
+    /// let mut offsets = Isaac::new(seed);
+    /// let expected: Vec<u8> = [0u8, 35, 255]
+    ///     .iter()
+    ///     .map(|opcode| opcode.wrapping_add(offsets.next_opcode_offset()))
+    ///     .collect();
+    /// assert_eq!(wtr, expected);
+    ///
+    /// let mut decoder = Isaac::new(seed);
+    /// let mut rdr = Cursor::new(wtr);
+    /// for opcode in [0, 35, 255] {
+    ///     assert_eq!(rdr.read_packet_opcode(&mut decoder).unwrap(), opcode);
+    /// }
+    /// ```
+    #[inline]
+    fn read_packet_opcode(&mut self, isaac: &mut Isaac) -> Result<u8> {
+        Ok(self.read_u8()?.wrapping_sub(isaac.next_opcode_offset()))
+    }
+
+    /// Reads a packet header, the opcode as [`ReadExt::read_packet_opcode`] followed by the size prefix `size_of` gives for that opcode
+    ///
+    /// Returns the opcode and the size of the body following the header.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{Isaac, PacketSize, ReadExt, WriteExt};
+    ///
+    /// let size_of = |opcode| match opcode {
+    ///     10 => PacketSize::Fixed(2),
+    ///     11 => PacketSize::VarByte,
+    ///     _ => PacketSize::VarShort,
+    /// };
+    ///
+    /// let seed = [1, 2, 3, 4];
+    /// let mut encoder = Isaac::new(seed);
+    /// let mut wtr = Vec::new();
+    /// wtr.write_game_packet(10, PacketSize::Fixed(2), &mut encoder, &[7, 8]).unwrap();
+    /// wtr.write_game_packet(11, PacketSize::VarByte, &mut encoder, b"hey").unwrap();
+    /// wtr.write_game_packet(12, PacketSize::VarShort, &mut encoder, &[0; 300]).unwrap();
+    ///
+    /// let mut decoder = Isaac::new(seed);
+    /// let mut rdr = Cursor::new(wtr);
+    /// assert_eq!(rdr.read_packet_header(&mut decoder, size_of).unwrap(), (10, 2));
+    /// assert_eq!(rdr.read_vec(2).unwrap(), vec![7, 8]);
+    /// assert_eq!(rdr.read_packet_header(&mut decoder, size_of).unwrap(), (11, 3));
+    /// assert_eq!(rdr.read_vec(3).unwrap(), b"hey");
+    /// assert_eq!(rdr.read_packet_header(&mut decoder, size_of).unwrap(), (12, 300));
+    /// assert_eq!(rdr.read_vec(300).unwrap(), vec![0; 300]);
+    /// ```
+    #[inline]
+    fn read_packet_header<F: Fn(u8) -> PacketSize>(
+        &mut self,
+        isaac: &mut Isaac,
+        size_of: F,
+    ) -> Result<(u8, usize)> {
+        let opcode = self.read_packet_opcode(isaac)?;
+        let len = match size_of(opcode) {
+            PacketSize::Fixed(len) => len,
+            PacketSize::VarByte => self.read_u8()? as usize,
+            PacketSize::VarShort => self.read_u16()? as usize,
+        };
+        Ok((opcode, len))
+    }
+
+    /// Reads an unsigned byte without advancing the reader
+    ///
+    /// The position is restored with `Seek` afterwards, even if the read fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![0x80, 0x80]);
+    /// assert_eq!(rdr.peek_u8().unwrap(), 0x80);
+    /// assert_eq!(rdr.position(), 0);
+    /// assert_eq!(rdr.read_u8().unwrap(), 0x80);
+    /// ```
+    #[inline]
+    fn peek_u8(&mut self) -> Result<u8>
+    where
+        Self: Seek,
+    {
+        peek(self, |r| r.read_u8())
+    }
+
+    /// Reads an unsigned short as big endian without advancing the reader
+    ///
+    /// The position is restored with `Seek` afterwards, even if the read fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![78, 97, 1]);
+    /// assert_eq!(rdr.peek_u16().unwrap(), 20065);
+    /// assert_eq!(rdr.read_u16().unwrap(), 20065);
+    /// assert!(rdr.peek_u16().is_err());
+    /// assert_eq!(rdr.read_u8().unwrap(), 1);
+    /// ```
+    #[inline]
+    fn peek_u16(&mut self) -> Result<u16>
+    where
+        Self: Seek,
+    {
+        peek(self, |r| r.read_u16())
+    }
+
+    /// Returns the width in bytes of the unsigned smart at the current position, without advancing the reader
+    ///
+    /// A first byte below 128 is a one byte smart, otherwise the smart takes two bytes, see [`ReadExt::read_u16_smart`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![65, 131, 218]);
+    /// assert_eq!(rdr.peek_smart_width().unwrap(), 1);
+    /// assert_eq!(rdr.position(), 0);
+    /// assert_eq!(rdr.read_u16_smart().unwrap(), 65);
+    ///
+    /// assert_eq!(rdr.peek_smart_width().unwrap(), 2);
+    /// assert_eq!(rdr.position(), 1);
+    /// assert_eq!(rdr.read_u16_smart().unwrap(), 986);
+    /// ```
+    #[inline]
+    fn peek_smart_width(&mut self) -> Result<usize>
+    where
+        Self: Seek,
+    {
+        Ok(if self.peek_u8()? < 128 { 1 } else { 2 })
+    }
+
+    /// Reads an unsigned short as smart, decoding both bytes from the reader's buffer when they are already in it
+    ///
+    /// This decodes the same values as [`ReadExt::read_u16_smart`], but takes both bytes of a two byte smart from the buffer at once instead of making two reads. That mostly pays off on a `BufReader`; reads from a `Cursor` or `&[u8]` are already cheap. When fewer than two bytes are buffered it falls back to [`ReadExt::read_u16_smart`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::{BufReader, Cursor};
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// for n in 0..=32767 {
+    ///     wtr.write_u16_smart(n).unwrap();
+    /// }
+    ///
+    /// let mut naive = Cursor::new(&wtr);
+    /// let mut fast = Cursor::new(&wtr);
+    /// let mut buffered = BufReader::with_capacity(3, &wtr[..]);
+    /// for _ in 0..=32767 {
+    ///     let n = naive.read_u16_smart().unwrap();
+    ///     assert_eq!(fast.read_u16_smart_buffered().unwrap(), n);
+    ///     assert_eq!(buffered.read_u16_smart_buffered().unwrap(), n);
+    /// }
+    /// assert!(fast.read_u16_smart_buffered().is_err());
+    /// ```
+    ///
+    /// ```rust
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr: &[u8] = &[0x80];
+    /// assert!(rdr.read_u16_smart_buffered().is_err());
+    /// ```
+    #[inline]
+    fn read_u16_smart_buffered(&mut self) -> Result<u16>
+    where
+        Self: BufRead,
+    {
+        let (n, width) = match self.fill_buf()? {
+            [peek, ..] if peek & 128 == 0 => (*peek as u16, 1),
+            [peek, next, ..] => (((*peek as u16 & 0x7F) << 8) | *next as u16, 2),
+            _ => return self.read_u16_smart(),
+        };
+        self.consume(width);
+        Ok(n)
+    }
+
+    /// Reads `len` bytes from the absolute `offset`, without moving the reader's position
+    ///
+    /// This suits formats that store a trailer with offsets into the data before it. The position is restored with `Seek` afterwards, even if the read fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::ReadExt;
+    ///
+    /// let mut rdr = Cursor::new(vec![10, 20, 30, 40, 50, 0, 3]);
+    /// rdr.set_position(5);
+    /// let offset = rdr.read_u16().unwrap() as usize;
+    /// assert_eq!(rdr.read_at(offset, 2).unwrap(), vec![40, 50]);
+    /// assert_eq!(rdr.position(), 7);
+    ///
+    /// assert!(rdr.read_at(6, 2).is_err());
+    /// assert_eq!(rdr.position(), 7);
+    /// ```
+    #[inline]
+    fn read_at(&mut self, offset: usize, len: usize) -> Result<Vec<u8>>
+    where
+        Self: Seek,
+    {
+        peek(self, |r| {
+            r.seek(SeekFrom::Start(offset as u64))?;
+            r.read_vec(len)
+        })
+    }
+
+    /// Reads an unsigned short in the given byte order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{Endianness, ReadExt};
+    ///
+    /// let buf = vec![1, 2];
+    /// assert_eq!(Cursor::new(&buf).read_u16_endian(Endianness::Big).unwrap(), 0x0102);
+    /// assert_eq!(Cursor::new(&buf).read_u16_endian(Endianness::Little).unwrap(), 0x0201);
+    /// ```
+    #[inline]
+    fn read_u16_endian(&mut self, endian: Endianness) -> Result<u16> {
+        match endian {
+            Endianness::Big => self.read_u16(),
+            Endianness::Little => self.read_u16_le(),
+        }
+    }
+
+    /// Reads an unsigned dword in the given byte order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{Endianness, ReadExt};
+    ///
+    /// let buf = vec![1, 2, 3, 4];
+    /// assert_eq!(Cursor::new(&buf).read_u32_endian(Endianness::Big).unwrap(), 0x01020304);
+    /// assert_eq!(Cursor::new(&buf).read_u32_endian(Endianness::Little).unwrap(), 0x04030201);
+    /// ```
+    #[inline]
+    fn read_u32_endian(&mut self, endian: Endianness) -> Result<u32> {
+        match endian {
+            Endianness::Big => self.read_u32(),
+            Endianness::Little => self.read_u32_le(),
+        }
+    }
+
+    /// Reads an unsigned qword in the given byte order
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{Endianness, ReadExt};
+    ///
+    /// let buf = vec![1, 2, 3, 4, 5, 6, 7, 8];
+    /// assert_eq!(Cursor::new(&buf).read_u64_endian(Endianness::Big).unwrap(), 0x0102030405060708);
+    /// assert_eq!(Cursor::new(&buf).read_u64_endian(Endianness::Little).unwrap(), 0x0807060504030201);
+    /// ```
+    #[inline]
+    fn read_u64_endian(&mut self, endian: Endianness) -> Result<u64> {
+        match endian {
+            Endianness::Big => self.read_u64(),
+            Endianness::Little => self.read_u64_le(),
+        }
+    }
+}
+
+impl<R: io::Read + ?Sized> ReadExt for R {}
+
+/// Reads `count` elements of `size` bytes, growing the buffer as bytes arrive rather than allocating it up front.
+fn read_bounded<R: Read + ?Sized>(rdr: &mut R, count: usize, size: usize) -> Result<Vec<u8>> {
+    let len = count.checked_mul(size).ok_or_else(|| {
+        OsrsError::Other(format!(
+            "Failed reading array, {} elements of {} bytes overflows",
+            count, size
+        ))
+    })?;
+    let mut buf = Vec::new();
+    rdr.take(len as u64).read_to_end(&mut buf)?;
+    if buf.len() != len {
+        return Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            format!(
+                "Failed reading array, expected {} bytes, got {}",
+                len,
+                buf.len()
+            ),
+        )
+        .into());
+    }
+    Ok(buf)
+}
+
+fn peek<R: Read + Seek + ?Sized, T>(rdr: &mut R, f: impl FnOnce(&mut R) -> Result<T>) -> Result<T> {
+    let pos = rdr.stream_position()?;
+    let value = f(rdr);
+    rdr.seek(SeekFrom::Start(pos))?;
+    value
+}
+
+/// Splits the trailing version off a cache container.
+///
+/// A container starts with a compression byte and a u32 length, followed by a u32 decompressed length when compressed, and then the data. A version is present when at least two bytes remain after that, and is read from them as a big endian u16. Without a version, or when the header is too short to tell, the data is returned as is.
+///
+/// # Examples
+///
+/// ```rust
+/// use osrs_bytes::split_version;
+///
+/// let data = [0, 0, 0, 0, 2, 7, 7, 0, 3];
+/// assert_eq!(split_version(&data), (&data[..7], Some(3)));
+///
+/// let data = [0, 0, 0, 0, 2, 7, 7];
+/// assert_eq!(split_version(&data), (&data[..], None));
+/// ```
+///
+/// ```rust
+/// use osrs_bytes::split_version;
+///
+/// let data = [1, 0, 0, 0, 1, 0, 0, 0, 9, 7, 1, 0];
+/// assert_eq!(split_version(&data), (&data[..10], Some(256)));
+/// ```
+pub fn split_version(data: &[u8]) -> (&[u8], Option<u16>) {
+    if data.len() < 5 {
+        return (data, None);
+    }
+    let len = u32::from_be_bytes([data[1], data[2], data[3], data[4]]) as usize;
+    let header: usize = if data[0] == 0 { 5 } else { 9 };
+    match header.checked_add(len) {
+        Some(end) if data.len().saturating_sub(end) >= 2 => {
+            let version = u16::from_be_bytes([data[end], data[end + 1]]);
+            (&data[..end], Some(version))
+        }
+        _ => (data, None),
+    }
+}
+
+/// The characters of CP1252 bytes 0x80 to 0x9F, with `'\0'` for the five bytes left undefined.
+///
+/// Every other byte maps to the code point of the same value.
+const CP1252_HIGH: [char; 32] = [
+    '\u{20AC}', '\0', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\0', '\u{017D}', '\0', '\0',
+    '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}', '\u{02DC}',
+    '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\0', '\u{017E}', '\u{0178}',
+];
+
+fn read_until_nul<R: Read + ?Sized>(rdr: &mut R) -> Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    loop {
+        match rdr.read_u8()? {
+            0 => return Ok(buf),
+            b => buf.push(b),
+        }
+    }
+}
+
+fn decode_cp1252_char(b: u8) -> Option<char> {
+    match b {
+        0x80..=0x9F => match CP1252_HIGH[(b - 0x80) as usize] {
+            '\0' => None,
+            c => Some(c),
+        },
+        _ => Some(b as char),
+    }
+}
+
+fn decode_cp1252(buf: &[u8]) -> Result<String> {
+    buf.iter()
+        .map(|&b| {
+            decode_cp1252_char(b).ok_or_else(|| {
+                OsrsError::Other(format!(
+                    "Failed decoding CP1252, byte {:#04X} is undefined",
+                    b
+                ))
+            })
+        })
+        .collect()
+}
+
+fn encode_cp1252(c: char) -> Result<u8> {
+    match c as u32 {
+        0..=0x7F | 0xA0..=0xFF => Ok(c as u8),
+        _ => CP1252_HIGH
+            .iter()
+            .position(|&h| h == c)
+            .map(|i| 0x80 + i as u8)
+            .ok_or_else(|| {
+                OsrsError::Other(format!(
+                    "Failed encoding CP1252, character {:?} has no byte",
+                    c
+                ))
+            }),
+    }
+}
+
+fn write_string_with_length<W: Write + ?Sized>(
+    wtr: &mut W,
+    prefix: LengthPrefix,
+    s: &str,
+) -> Result<()> {
+    let buf = s.chars().map(encode_cp1252).collect::<Result<Vec<u8>>>()?;
+    wtr.write_length(prefix, buf.len())?;
+    Ok(wtr.write_all(&buf)?)
+}
+
+pub trait WriteExt: Write {
+    /// Writes an unsigned byte to the writer.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u8(42).unwrap();
+    /// assert_eq!(wtr[0], 42);
+    /// ```
+    #[inline]
+    fn write_u8(&mut self, n: u8) -> Result<()> {
+        Ok(self.write_all(&[n])?)
+    }
+
+    /// Writes an unsigned byte to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u8_add(42).unwrap();
+    /// assert_eq!(wtr[0], 170);
+    /// ```
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u8_add(128).unwrap();
+    /// assert_eq!(wtr[0], 0);
+    /// ```
+    #[inline]
+    fn write_u8_add(&mut self, n: u8) -> Result<()> {
+        self.write_u8(n.wrapping_add(128))
+    }
+
+    /// Writes an unsigned byte to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u8_neg(42).unwrap();
+    /// assert_eq!(wtr[0], 214);
+    /// ```
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u8_neg(0).unwrap();
+    /// assert_eq!(wtr[0], 0);
+    /// ```
+    #[inline]
+    fn write_u8_neg(&mut self, n: u8) -> Result<()> {
+        self.write_u8(0u8.wrapping_sub(n))
+    }
+
+    /// Writes an unsigned byte to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u8_sub(42).unwrap();
+    /// assert_eq!(wtr[0], 86);
+    /// ```
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u8_sub(128).unwrap();
+    /// assert_eq!(wtr[0], 0);
+    /// ```
+    #[inline]
+    fn write_u8_sub(&mut self, n: u8) -> Result<()> {
+        self.write_u8(128u8.wrapping_sub(n))
+    }
+
+    /// Writes an signed byte to the writer.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_i8(-67).unwrap();
+    /// assert_eq!(wtr[0] as i8, -67);
+    /// ```
+    #[inline]
+    fn write_i8(&mut self, n: i8) -> Result<()> {
+        self.write_u8(n as u8)
+    }
+
+    /// Writes the number 128, subtracted by the signed byte to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_i8_sub(99).unwrap();
+    /// assert_eq!(wtr[0] as i8, 29);
+    /// ```
+    #[inline]
+    fn write_i8_sub(&mut self, n: i8) -> Result<()> {
+        self.write_u8_sub(n as u8)
+    }
+
+    /// Writes the byte and adds 128.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_i8_add(42).unwrap();
+    /// assert_eq!(wtr[0], 170);
+    /// ```
+    #[inline]
+    fn write_i8_add(&mut self, n: i8) -> Result<()> {
+        self.write_u8_add(n as u8)
+    }
+
+    /// Writes a negated byte to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_i8_neg(55).unwrap();
+    /// assert_eq!(wtr[0], 201);
+    /// ```
+    #[inline]
+    fn write_i8_neg(&mut self, n: i8) -> Result<()> {
+        self.write_u8_neg(n as u8)
+    }
+
+    /// Writes a bool to the writer.
+    ///
+    /// Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_bool(true).unwrap();
+    /// assert_eq!(wtr[0], 1);
+    /// ```
+    #[inline]
+    fn write_bool(&mut self, b: bool) -> Result<()> {
+        Ok(self.write_all(&[b as u8])?)
+    }
+
+    /// Writes a tristate to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::{Tristate, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_tristate(Tristate::No).unwrap();
+    /// wtr.write_tristate(Tristate::Yes).unwrap();
+    /// wtr.write_tristate(Tristate::Inherit).unwrap();
+    /// assert_eq!(wtr, vec![0, 1, 2]);
+    /// ```
+    #[inline]
+    fn write_tristate(&mut self, t: Tristate) -> Result<()> {
+        self.write_u8(t as u8)
+    }
+
+    /// Writes an unsigned short to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u16(20065).unwrap();
+    /// assert_eq!(wtr[0], 78);
+    /// assert_eq!(wtr[1], 97);
+    /// ```
+    #[inline]
+    fn write_u16(&mut self, n: u16) -> Result<()> {
+        Ok(self.write_all(&n.to_be_bytes())?)
+    }
+
+    /// Writes an unsigned short as a little endian to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u16_le(29543).unwrap();
+    /// assert_eq!(wtr[0], 103);
+    /// assert_eq!(wtr[1], 115);
+    /// ```
+    ///
+    #[inline]
+    fn write_u16_le(&mut self, n: u16) -> Result<()> {
+        Ok(self.write_all(&n.to_le_bytes())?)
+    }
+
+    /// Writes an unsigned short add to the writer.
+    ///
+    /// The high byte comes first, followed by the low byte with 128 added.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u16_add(25346).unwrap();
+    /// assert_eq!(wtr, vec![99, 130]);
+    ///
+    /// let mut wtr = Vec::new();
+    /// for n in [0, 127, 128, 255, 0x8000, u16::MAX] {
+    ///     wtr.write_u16_add(n).unwrap();
+    /// }
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in [0, 127, 128, 255, 0x8000, u16::MAX] {
+    ///     assert_eq!(rdr.read_u16_add().unwrap(), n);
+    /// }
+    /// ```
+    #[inline]
+    fn write_u16_add(&mut self, n: u16) -> Result<()> {
+        self.write_u8((n >> 8) as u8)?;
+        self.write_u8((n as u8).wrapping_add(128))
+    }
+
+    /// Writes an unsigned short add as little endian to the writer.
+    ///
+    /// The low byte with 128 added comes first, followed by the high byte.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u16_add_le(17113).unwrap();
+    /// assert_eq!(wtr, vec![89, 66]);
+    ///
+    /// let mut wtr = Vec::new();
+    /// for n in [0, 127, 128, 255, 0x8000, u16::MAX] {
+    ///     wtr.write_u16_add_le(n).unwrap();
+    /// }
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in [0, 127, 128, 255, 0x8000, u16::MAX] {
+    ///     assert_eq!(rdr.read_u16_add_le().unwrap(), n);
+    /// }
+    /// ```
+    #[inline]
+    fn write_u16_add_le(&mut self, n: u16) -> Result<()> {
+        self.write_u8((n as u8).wrapping_add(128))?;
+        self.write_u8((n >> 8) as u8)
+    }
+
+    /// Writes an unsigned short smart to the writer.
+    ///
+    /// # Examples
+    ///
+    /// Writing a value lesser than or equal to 127 makes it write out a single unsigned byte.
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u16_smart(65).unwrap();
+    /// assert_eq!(wtr[0], 65);
+    /// assert!(wtr.get(1).is_none());
+    /// ```
+    ///
+    /// Writing a value greater than 127 will make it write out two unsigned bytes.
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u16_smart(986).unwrap();
+    /// assert_eq!(wtr[0], 131);
+    /// assert_eq!(wtr[1], 218);
+    /// ```
+    ///
+    /// An out of range value is an error and nothing is written, so a packet is never left with half a field. This holds for all the smart writers.
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u16(0xCAFE).unwrap();
+    /// assert!(wtr.write_u16_smart(32768).is_err());
+    /// assert!(wtr.write_i16_smart(-16385).is_err());
+    /// assert!(wtr.write_u32_smart(0x80000000).is_err());
+    /// assert!(wtr.write_u32_big_smart(0x40000000).is_err());
+    /// assert_eq!(wtr, vec![0xCA, 0xFE]);
+    /// ```
+    ///
+    #[inline]
+    fn write_u16_smart(&mut self, n: u16) -> Result<()> {
+        match n {
+            0..=127 => self.write_u8(n as u8),
+            128..=32767 => self.write_u16(n + 32768),
+            _ => Err(OsrsError::SmartOutOfRange(n.into())),
+        }
+    }
+
+    /// Writes a signed short smart to the writer.
+    ///
+    /// # Examples
+    ///
+    /// Writing a value from -64 to 63 makes it write out a single unsigned byte, offset by 64.
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_i16_smart(-64).unwrap();
+    /// wtr.write_i16_smart(63).unwrap();
+    /// assert_eq!(wtr, vec![0, 127]);
+    /// ```
+    ///
+    /// Any other value from -16384 to 16383 makes it write out two unsigned bytes, offset by 49152.
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_i16_smart(-65).unwrap();
+    /// wtr.write_i16_smart(64).unwrap();
+    /// wtr.write_i16_smart(-16384).unwrap();
+    /// wtr.write_i16_smart(16383).unwrap();
+    /// assert_eq!(wtr, vec![0xBF, 0xBF, 0xC0, 0x40, 0x80, 0x00, 0xFF, 0xFF]);
+    /// ```
+    ///
+    /// Values outside of that range are an error.
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// assert!(wtr.write_i16_smart(-16385).is_err());
+    /// assert!(wtr.write_i16_smart(16384).is_err());
+    /// assert!(wtr.is_empty());
+    /// ```
+    ///
+    /// Every value round-trips through [`ReadExt::read_i16_smart`].
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// for n in -16384..=16383 {
+    ///     wtr.write_i16_smart(n).unwrap();
+    /// }
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in -16384..=16383 {
+    ///     assert_eq!(rdr.read_i16_smart().unwrap(), n);
+    /// }
+    /// ```
+    #[inline]
+    fn write_i16_smart(&mut self, n: i16) -> Result<()> {
+        match n {
+            -64..=63 => self.write_u8((n + 64) as u8),
+            -16384..=16383 => self.write_u16((n as i32 + 49152) as u16),
+            _ => Err(OsrsError::SmartOutOfRange(n.into())),
+        }
+    }
+
+    /// Writes an unsigned dword smart to the writer.
+    ///
+    /// # Examples
+    ///
+    /// Writing a value lesser than or equal to 32767 makes it write out an unsigned short.
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u32_smart(32767).unwrap();
+    /// assert_eq!(wtr, vec![0x7F, 0xFF]);
+    /// ```
+    ///
+    /// Writing a value greater than 32767 makes it write out an unsigned dword with the high bit set.
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u32_smart(32768).unwrap();
+    /// wtr.write_u32_smart(i32::MAX as u32).unwrap();
+    /// assert_eq!(wtr, vec![0x80, 0x00, 0x80, 0x00, 0xFF, 0xFF, 0xFF, 0xFF]);
+    /// ```
+    ///
+    /// Values above `i32::MAX` are an error.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// assert!(wtr.write_u32_smart(i32::MAX as u32 + 1).is_err());
+    /// assert!(wtr.is_empty());
+    ///
+    /// for n in [0, 32767, 32768, i32::MAX as u32] {
+    ///     wtr.write_u32_smart(n).unwrap();
+    /// }
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in [0, 32767, 32768, i32::MAX as u32] {
+    ///     assert_eq!(rdr.read_u32_smart().unwrap(), n);
+    /// }
+    /// ```
+    #[inline]
+    fn write_u32_smart(&mut self, n: u32) -> Result<()> {
+        match n {
+            0..=32767 => self.write_u16(n as u16),
+            32768..=0x7FFFFFFF => self.write_u32(n | 0x80000000),
+            _ => Err(OsrsError::SmartOutOfRange(n.into())),
+        }
+    }
+
+    /// Writes a signed short to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_i16(-14632).unwrap();
+    /// assert_eq!(wtr[0], 198);
+    /// assert_eq!(wtr[1], 216);
+    /// ```
+    #[inline]
+    fn write_i16(&mut self, n: i16) -> Result<()> {
+        self.write_u16(n as u16)
+    }
+
+    /// Writes a signed short as little endian to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_i16_le(-7654).unwrap();
+    /// assert_eq!(wtr[0], 26);
+    /// assert_eq!(wtr[1], 226);
+    /// ```
+    #[inline]
+    fn write_i16_le(&mut self, n: i16) -> Result<()> {
+        self.write_u16_le(n as u16)
+    }
+
+    /// Writes a signed short add to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_i16_add(-9867).unwrap();
+    /// assert_eq!(wtr[0], 217);
+    /// assert_eq!(wtr[1], 245);
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// for n in [i16::MIN, -1, 0, 32639, 32640, i16::MAX] {
+    ///     wtr.write_i16_add(n).unwrap();
+    ///     wtr.write_i16_add_le(n).unwrap();
+    /// }
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in [i16::MIN, -1, 0, 32639, 32640, i16::MAX] {
+    ///     assert_eq!(rdr.read_i16_add().unwrap(), n);
+    ///     assert_eq!(rdr.read_i16_add_le().unwrap(), n);
+    /// }
+    /// ```
+    #[inline]
+    fn write_i16_add(&mut self, n: i16) -> Result<()> {
+        self.write_u16_add(n as u16)
+    }
+
+    /// Writes a signed short add as a little endian to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_i16_add_le(-12632).unwrap();
+    /// assert_eq!(wtr[0], 40);
+    /// assert_eq!(wtr[1], 206);
+    /// ```
+    ///
+    #[inline]
+    fn write_i16_add_le(&mut self, n: i16) -> Result<()> {
+        self.write_u16_add_le(n as u16)
+    }
+
+    /// Writes an unsigned medium to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u24(66051).unwrap();
+    /// wtr.write_u24(0xFFFFFF).unwrap();
+    /// assert_eq!(wtr, vec![1, 2, 3, 255, 255, 255]);
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// assert_eq!(rdr.read_u24().unwrap(), 66051);
+    /// assert_eq!(rdr.read_u24().unwrap(), 16777215);
+    /// ```
+    ///
+    /// Values above 0xFFFFFF are an error.
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// assert!(wtr.write_u24(0x1000000).is_err());
+    /// assert!(wtr.is_empty());
+    /// ```
+    #[inline]
+    fn write_u24(&mut self, n: u32) -> Result<()> {
+        if n > 0xFFFFFF {
+            return Err(OsrsError::Other(format!(
+                "Failed writing medium, value is {}",
+                n
+            )));
+        }
+        Ok(self.write_all(&n.to_be_bytes()[1..])?)
+    }
+
+    /// Writes an unsigned medium as little endian to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u24_le(66051).unwrap();
+    /// wtr.write_u24_le(0xFFFFFF).unwrap();
+    /// assert_eq!(wtr, vec![3, 2, 1, 255, 255, 255]);
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// assert_eq!(rdr.read_u24_le().unwrap(), 66051);
+    /// assert_eq!(rdr.read_u24_le().unwrap(), 16777215);
+    /// ```
+    ///
+    /// Values above 0xFFFFFF are an error.
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// assert!(wtr.write_u24_le(0x1000000).is_err());
+    /// assert!(wtr.is_empty());
+    /// ```
+    #[inline]
+    fn write_u24_le(&mut self, n: u32) -> Result<()> {
+        if n > 0xFFFFFF {
+            return Err(OsrsError::Other(format!(
+                "Failed writing medium, value is {}",
+                n
+            )));
+        }
+        Ok(self.write_all(&n.to_le_bytes()[..3])?)
+    }
+
+    /// Writes a signed medium to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// for n in [-8388608, -1, 0, 8388607] {
+    ///     wtr.write_i24(n).unwrap();
+    /// }
+    /// assert_eq!(wtr, vec![128, 0, 0, 255, 255, 255, 0, 0, 0, 127, 255, 255]);
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in [-8388608, -1, 0, 8388607] {
+    ///     assert_eq!(rdr.read_i24().unwrap(), n);
+    /// }
+    /// ```
+    ///
+    /// Values outside of -8388608 to 8388607 are an error.
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// assert!(wtr.write_i24(-8388609).is_err());
+    /// assert!(wtr.write_i24(8388608).is_err());
+    /// assert!(wtr.is_empty());
+    /// ```
+    #[inline]
+    fn write_i24(&mut self, n: i32) -> Result<()> {
+        if !(-0x800000..=0x7FFFFF).contains(&n) {
+            return Err(OsrsError::Other(format!(
+                "Failed writing medium, value is {}",
+                n
+            )));
+        }
+        self.write_u24(n as u32 & 0xFFFFFF)
+    }
+
+    /// Writes a signed medium as little endian to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// for n in [-8388608, -1, 0, 8388607] {
+    ///     wtr.write_i24_le(n).unwrap();
+    /// }
+    /// assert_eq!(wtr, vec![0, 0, 128, 255, 255, 255, 0, 0, 0, 255, 255, 127]);
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in [-8388608, -1, 0, 8388607] {
+    ///     assert_eq!(rdr.read_i24_le().unwrap(), n);
+    /// }
+    /// ```
+    ///
+    /// Values outside of -8388608 to 8388607 are an error.
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// assert!(wtr.write_i24_le(8388608).is_err());
+    /// assert!(wtr.is_empty());
+    /// ```
+    #[inline]
+    fn write_i24_le(&mut self, n: i32) -> Result<()> {
+        if !(-0x800000..=0x7FFFFF).contains(&n) {
+            return Err(OsrsError::Other(format!(
+                "Failed writing medium, value is {}",
+                n
+            )));
+        }
+        self.write_u24_le(n as u32 & 0xFFFFFF)
+    }
+
+    /// Writes an unsigned dword to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u32(98571).unwrap();
+    /// assert_eq!(wtr[0], 0);
+    /// assert_eq!(wtr[1], 1);
+    /// assert_eq!(wtr[2], 129);
+    /// assert_eq!(wtr[3], 11);
+    /// ```
+    ///
+    #[inline]
+    fn write_u32(&mut self, n: u32) -> Result<()> {
+        Ok(self.write_all(&n.to_be_bytes())?)
+    }
+
+    /// Writes am unsigned integer as little endian to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u32_le(26904).unwrap();
+    /// assert_eq!(wtr[0], 24);
+    /// assert_eq!(wtr[1], 105);
+    /// assert_eq!(wtr[2], 0);
+    /// assert_eq!(wtr[3], 0);
+    /// ```
+    ///
+    #[inline]
+    fn write_u32_le(&mut self, n: u32) -> Result<()> {
+        Ok(self.write_all(&n.to_le_bytes())?)
+    }
+
+    /// Writes an unsigned dword as a middle endian to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u32_me(83964169).unwrap();
+    /// assert_eq!(wtr, vec![1, 5, 9, 49]);
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// for n in [0, 1, 0x12345678, 0xFFFF0000, u32::MAX] {
+    ///     wtr.write_u32_me(n).unwrap();
+    /// }
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in [0, 1, 0x12345678, 0xFFFF0000, u32::MAX] {
+    ///     assert_eq!(rdr.read_u32_me().unwrap(), n);
+    /// }
+    /// ```
+    #[inline]
+    fn write_u32_me(&mut self, n: u32) -> Result<()> {
+        self.write_u16_le((n >> 16) as u16)?;
+        self.write_u16_le(n as u16)
+    }
+
+    /// Writes an unsigned dword as an inversed middle endian to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u32_ime(9764864).unwrap();
+    /// assert_eq!(wtr, vec![0, 0, 0, 149]);
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// for n in [0, 1, 0x12345678, 0xFFFF0000, u32::MAX] {
+    ///     wtr.write_u32_ime(n).unwrap();
+    /// }
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in [0, 1, 0x12345678, 0xFFFF0000, u32::MAX] {
+    ///     assert_eq!(rdr.read_u32_ime().unwrap(), n);
+    /// }
+    /// ```
+    #[inline]
+    fn write_u32_ime(&mut self, n: u32) -> Result<()> {
+        self.write_u16(n as u16)?;
+        self.write_u16((n >> 16) as u16)
+    }
+
+    /// Writes a signed dword to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_i32(-131045).unwrap();
+    /// assert_eq!(wtr[0], 255);
+    /// assert_eq!(wtr[1], 254);
+    /// assert_eq!(wtr[2], 0);
+    /// assert_eq!(wtr[3], 27);
+    /// ```
+    ///
+    #[inline]
+    fn write_i32(&mut self, n: i32) -> Result<()> {
+        self.write_u32(n as u32)
+    }
+
+    /// Writes a signed integer as little endian to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_i32_le(18879).unwrap();
+    /// assert_eq!(wtr[0], 191);
+    /// assert_eq!(wtr[1], 73);
+    /// assert_eq!(wtr[2], 0);
+    /// assert_eq!(wtr[3], 0);
+    /// ```
+    ///
+    #[inline]
+    fn write_i32_le(&mut self, n: i32) -> Result<()> {
+        self.write_u32_le(n as u32)
+    }
+
+    /// Writes a signed dword as a middle endian to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_i32_me(-98231).unwrap();
+    /// assert_eq!(wtr[0], 254);
+    /// assert_eq!(wtr[1], 255);
+    /// assert_eq!(wtr[2], 73);
+    /// assert_eq!(wtr[3], 128);
+    /// ```
+    ///
+    /// The layout is the same as [`WriteExt::write_u32_me`], so every value reads back with [`ReadExt::read_i32_me`].
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_i32_me(0xAABBCCDDu32 as i32).unwrap();
+    /// assert_eq!(wtr, vec![0xBB, 0xAA, 0xDD, 0xCC]);
+    ///
+    /// let values: Vec<i32> = (i32::MIN..=i32::MAX)
+    ///     .step_by(65_521)
+    ///     .chain([i32::MAX, -1, 0, 1, 0x7FFF, 0x8000, -0x8000, 0xFFFF, 0x10000])
+    ///     .collect();
+    /// let mut wtr = Vec::new();
+    /// for n in &values {
+    ///     wtr.write_i32_me(*n).unwrap();
+    ///     wtr.write_i32_ime(*n).unwrap();
+    /// }
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in values {
+    ///     assert_eq!(rdr.read_i32_me().unwrap(), n);
+    ///     assert_eq!(rdr.read_i32_ime().unwrap(), n);
+    /// }
+    /// ```
+    #[inline]
+    fn write_i32_me(&mut self, n: i32) -> Result<()> {
+        self.write_u32_me(n as u32)
+    }
+
+    /// Writes a signed dword as an inversed middle endian to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_i32_ime(-98231).unwrap();
+    /// assert_eq!(wtr[0], 128);
+    /// assert_eq!(wtr[1], 73);
+    /// assert_eq!(wtr[2], 255);
+    /// assert_eq!(wtr[3], 254);
+    /// ```
+    ///
+    #[inline]
+    fn write_i32_ime(&mut self, n: i32) -> Result<()> {
+        self.write_u32_ime(n as u32)
+    }
+
+    /// Writes an unsigned qword to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u64(8589934592).unwrap();
+    /// assert_eq!(wtr[0], 0);
+    /// assert_eq!(wtr[1], 0);
+    /// assert_eq!(wtr[2], 0);
+    /// assert_eq!(wtr[3], 2);
+    /// assert_eq!(wtr[4], 0);
+    /// assert_eq!(wtr[5], 0);
+    /// assert_eq!(wtr[6], 0);
+    /// assert_eq!(wtr[7], 0);
+    /// ```
+    ///
+    #[inline]
+    fn write_u64(&mut self, n: u64) -> Result<()> {
+        Ok(self.write_all(&n.to_be_bytes())?)
+    }
+
+    /// Writes an unsigned qword as little endian to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u64_le(0x0102030405060708).unwrap();
+    /// assert_eq!(wtr, vec![8, 7, 6, 5, 4, 3, 2, 1]);
+    /// ```
+    #[inline]
+    fn write_u64_le(&mut self, n: u64) -> Result<()> {
+        Ok(self.write_all(&n.to_le_bytes())?)
+    }
+
+    /// Writes an unsigned qword as middle endian to the writer.
+    ///
+    /// The high dword is written first and the low dword second, each as little endian. This differs from plain little endian, which writes the low dword first.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut me = Vec::new();
+    /// me.write_u64_me(0x0102030405060708).unwrap();
+    /// assert_eq!(me, vec![4, 3, 2, 1, 8, 7, 6, 5]);
+    ///
+    /// let mut le = Vec::new();
+    /// le.write_u64_le(0x0102030405060708).unwrap();
+    /// assert_ne!(me, le);
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// for n in [0, 1, 0xDEADBEEF, 0xFFFFFFFF00000000, u64::MAX] {
+    ///     wtr.write_u64_me(n).unwrap();
+    ///     wtr.write_u64_le(n).unwrap();
+    /// }
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in [0, 1, 0xDEADBEEF, 0xFFFFFFFF00000000, u64::MAX] {
+    ///     assert_eq!(rdr.read_u64_me().unwrap(), n);
+    ///     assert_eq!(rdr.read_u64_le().unwrap(), n);
+    /// }
+    /// ```
+    #[inline]
+    fn write_u64_me(&mut self, n: u64) -> Result<()> {
+        self.write_u32_le((n >> 32) as u32)?;
+        self.write_u32_le(n as u32)
+    }
+
+    /// Writes a signed qword to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_i64(-8589934592).unwrap();
+    /// assert_eq!(wtr[0], 255);
+    /// assert_eq!(wtr[1], 255);
+    /// assert_eq!(wtr[2], 255);
+    /// assert_eq!(wtr[3], 254);
+    /// assert_eq!(wtr[4], 0);
+    /// assert_eq!(wtr[5], 0);
+    /// assert_eq!(wtr[6], 0);
+    /// assert_eq!(wtr[7], 0);
+    /// ```
+    ///
+    #[inline]
+    fn write_i64(&mut self, n: i64) -> Result<()> {
+        self.write_u64(n as u64)
+    }
+
+    /// Writes a signed qword as little endian to the writer.
     ///
     /// # Examples
     ///
@@ -1023,31 +4546,487 @@ pub trait WriteExt: Write {
     /// use osrs_bytes::WriteExt;
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_i8_sub(99).unwrap();
-    /// assert_eq!(wtr[0] as i8, 29);
+    /// wtr.write_i64_le(0x0102030405060708).unwrap();
+    /// assert_eq!(wtr, vec![8, 7, 6, 5, 4, 3, 2, 1]);
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// for n in [0x0102030405060708, -0x0102030405060708, i64::MIN, i64::MAX] {
+    ///     wtr.write_i64_le(n).unwrap();
+    /// }
+    /// wtr.write_u64_le(0xF1F2F3F4F5F6F7F8).unwrap();
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in [0x0102030405060708, -0x0102030405060708, i64::MIN, i64::MAX] {
+    ///     assert_eq!(rdr.read_i64_le().unwrap(), n);
+    /// }
+    /// assert_eq!(rdr.read_u64_le().unwrap(), 0xF1F2F3F4F5F6F7F8);
+    /// assert!(rdr.read_u8().is_err());
     /// ```
     #[inline]
-    fn write_i8_sub(&mut self, n: i8) -> Result<()> {
-        self.write_u8_sub(n as u8)
+    fn write_i64_le(&mut self, n: i64) -> Result<()> {
+        self.write_u64_le(n as u64)
     }
 
-    /// Writes the byte and adds 128.
+    /// Writes a float as big endian to the writer.
+    ///
+    /// The bit pattern is kept exactly, including for NaN and infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_f32(1.0).unwrap();
+    /// assert_eq!(wtr, vec![63, 128, 0, 0]);
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let values = [0.0, -0.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY];
+    /// let mut wtr = Vec::new();
+    /// for n in values {
+    ///     wtr.write_f32(n).unwrap();
+    ///     wtr.write_f32_le(n).unwrap();
+    /// }
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in values {
+    ///     assert_eq!(rdr.read_f32().unwrap().to_bits(), n.to_bits());
+    ///     assert_eq!(rdr.read_f32_le().unwrap().to_bits(), n.to_bits());
+    /// }
+    /// ```
+    #[inline]
+    fn write_f32(&mut self, n: f32) -> Result<()> {
+        Ok(self.write_all(&n.to_be_bytes())?)
+    }
+
+    /// Writes a float as little endian to the writer.
+    ///
+    /// The bit pattern is kept exactly, including for NaN and infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_f32_le(1.0).unwrap();
+    /// assert_eq!(wtr, vec![0, 0, 128, 63]);
+    /// ```
+    #[inline]
+    fn write_f32_le(&mut self, n: f32) -> Result<()> {
+        Ok(self.write_all(&n.to_le_bytes())?)
+    }
+
+    /// Writes a double as big endian to the writer.
+    ///
+    /// The bit pattern is kept exactly, including for NaN and infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_f64(-2.5).unwrap();
+    /// assert_eq!(wtr, vec![192, 4, 0, 0, 0, 0, 0, 0]);
+    /// ```
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let values = [0.0, -0.0, f64::NAN, f64::INFINITY, f64::NEG_INFINITY];
+    /// let mut wtr = Vec::new();
+    /// for n in values {
+    ///     wtr.write_f64(n).unwrap();
+    ///     wtr.write_f64_le(n).unwrap();
+    /// }
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// for n in values {
+    ///     assert_eq!(rdr.read_f64().unwrap().to_bits(), n.to_bits());
+    ///     assert_eq!(rdr.read_f64_le().unwrap().to_bits(), n.to_bits());
+    /// }
+    /// ```
+    #[inline]
+    fn write_f64(&mut self, n: f64) -> Result<()> {
+        Ok(self.write_all(&n.to_be_bytes())?)
+    }
+
+    /// Writes a double as little endian to the writer.
+    ///
+    /// The bit pattern is kept exactly, including for NaN and infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_f64_le(-2.5).unwrap();
+    /// assert_eq!(wtr, vec![0, 0, 0, 0, 0, 0, 4, 192]);
+    /// ```
+    #[inline]
+    fn write_f64_le(&mut self, n: f64) -> Result<()> {
+        Ok(self.write_all(&n.to_le_bytes())?)
+    }
+
+    /// Writes a CP1252 string to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_string_cp1252("hello").unwrap();
+    /// assert_eq!(wtr[0], 104);
+    /// assert_eq!(wtr[1], 101);
+    /// assert_eq!(wtr[2], 108);
+    /// assert_eq!(wtr[3], 108);
+    /// assert_eq!(wtr[4], 111);
+    /// assert_eq!(wtr[5], 0);
+    /// ```
+    ///
+    /// Each character is written as its single CP1252 byte, so the string reads back the same with [`ReadExt::read_string_cp1252`].
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_string_cp1252("5\u{20AC} caf\u{E9} \u{2013} ok").unwrap();
+    /// assert_eq!(
+    ///     wtr,
+    ///     vec![0x35, 0x80, 0x20, 0x63, 0x61, 0x66, 0xE9, 0x20, 0x96, 0x20, 0x6F, 0x6B, 0]
+    /// );
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// assert_eq!(rdr.read_string_cp1252().unwrap(), "5\u{20AC} caf\u{E9} \u{2013} ok");
+    /// ```
+    ///
+    /// A character with no CP1252 byte is an error and nothing is written.
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// assert!(wtr.write_string_cp1252("snow\u{2603}").is_err());
+    /// assert!(wtr.is_empty());
+    /// ```
+    #[inline]
+    fn write_string_cp1252<T: AsRef<str>>(&mut self, s: T) -> Result<()> {
+        let mut buf = s
+            .as_ref()
+            .chars()
+            .map(encode_cp1252)
+            .collect::<Result<Vec<u8>>>()?;
+        buf.push(0);
+        Ok(self.write_all(&buf)?)
+    }
+
+    /// Writes a versioned CP1252 string to the writer, a zero version byte followed by a null terminated string.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_versioned_string("hello").unwrap();
+    /// assert_eq!(wtr, vec![0, 104, 101, 108, 108, 111, 0]);
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// assert_eq!(rdr.read_versioned_string().unwrap(), "hello");
+    /// ```
+    ///
+    /// A character with no CP1252 byte is an error and nothing is written, not even the version byte.
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// assert!(wtr.write_versioned_string("\u{2603}").is_err());
+    /// assert!(wtr.is_empty());
+    /// ```
+    #[inline]
+    fn write_versioned_string<T: AsRef<str>>(&mut self, s: T) -> Result<()> {
+        let mut buf = vec![0];
+        buf.write_string_cp1252(s)?;
+        Ok(self.write_all(&buf)?)
+    }
+
+    /// Writes a CP1252 string prefixed with its length as an unsigned byte to the writer, without a terminator.
+    ///
+    /// A string longer than 255 bytes is an error and nothing is written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_string_prefixed("hi").unwrap();
+    /// wtr.write_string_prefixed("").unwrap();
+    /// assert_eq!(wtr, vec![2, 104, 105, 0]);
+    ///
+    /// let long = "a".repeat(255);
+    /// let mut wtr = Vec::new();
+    /// wtr.write_string_prefixed(&long).unwrap();
+    /// assert_eq!(wtr.len(), 256);
+    /// assert_eq!(Cursor::new(wtr).read_string_prefixed().unwrap(), long);
+    ///
+    /// let mut wtr = Vec::new();
+    /// assert!(wtr.write_string_prefixed("a".repeat(256)).is_err());
+    /// assert!(wtr.is_empty());
+    /// ```
+    #[inline]
+    fn write_string_prefixed<T: AsRef<str>>(&mut self, s: T) -> Result<()> {
+        write_string_with_length(self, LengthPrefix::U8, s.as_ref())
+    }
+
+    /// Writes a CP1252 string prefixed with its length as an unsigned short to the writer, without a terminator.
+    ///
+    /// A string longer than 65535 bytes is an error and nothing is written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_string_prefixed_u16("hi").unwrap();
+    /// assert_eq!(wtr, vec![0, 2, 104, 105]);
+    /// assert_eq!(Cursor::new(wtr).read_string_prefixed_u16().unwrap(), "hi");
+    ///
+    /// let mut wtr = Vec::new();
+    /// assert!(wtr.write_string_prefixed_u16("a".repeat(65536)).is_err());
+    /// assert!(wtr.is_empty());
+    /// ```
+    #[inline]
+    fn write_string_prefixed_u16<T: AsRef<str>>(&mut self, s: T) -> Result<()> {
+        write_string_with_length(self, LengthPrefix::U16, s.as_ref())
+    }
+
+    /// Writes a value of the type given by a param or enum type char to the writer.
+    ///
+    /// A value that does not match the type char is an error and nothing is written, see [`ReadExt::read_typed_value`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, TypedValue, WriteExt};
+    ///
+    /// let values = [
+    ///     ('i', TypedValue::Int(-2)),
+    ///     ('s', TypedValue::Str("hi".to_owned())),
+    ///     ('o', TypedValue::Obj(1234)),
+    ///     ('I', TypedValue::Component(786435)),
+    /// ];
+    /// let mut wtr = Vec::new();
+    /// for (type_code, value) in &values {
+    ///     wtr.write_typed_value(*type_code, value).unwrap();
+    /// }
+    /// assert_eq!(wtr, vec![255, 255, 255, 254, 104, 105, 0, 0, 0, 4, 210, 0, 12, 0, 3]);
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// for (type_code, value) in values {
+    ///     assert_eq!(rdr.read_typed_value(type_code).unwrap(), value);
+    /// }
+    /// ```
+    ///
+    /// ```rust
+    /// use osrs_bytes::{TypedValue, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// assert!(wtr.write_typed_value('i', &TypedValue::Str("hi".to_owned())).is_err());
+    /// assert!(wtr.write_typed_value('o', &TypedValue::Component(1)).is_err());
+    /// assert!(wtr.is_empty());
+    /// ```
+    #[inline]
+    fn write_typed_value(&mut self, type_code: char, value: &TypedValue) -> Result<()> {
+        match (type_code, value) {
+            ('i', TypedValue::Int(n)) => self.write_i32(*n),
+            ('s', TypedValue::Str(s)) => self.write_string_cp1252(s),
+            ('o', TypedValue::Obj(id)) | ('I', TypedValue::Component(id)) => self.write_u32(*id),
+            _ => Err(OsrsError::Other(format!(
+                "Failed writing typed value, {:?} does not match type {:?}",
+                value, type_code
+            ))),
+        }
+    }
+
+    /// Writes a length encoded as the given prefix to the writer.
+    ///
+    /// A length that does not fit in the prefix is an error and nothing is written, see [`ReadExt::read_length`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::{LengthPrefix, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_length(LengthPrefix::U8, 5).unwrap();
+    /// wtr.write_length(LengthPrefix::U16, 5).unwrap();
+    /// wtr.write_length(LengthPrefix::Smart, 128).unwrap();
+    /// assert_eq!(wtr, vec![5, 0, 5, 0x80, 0x80]);
+    /// assert!(wtr.write_length(LengthPrefix::U8, 256).is_err());
+    /// assert!(wtr.write_length(LengthPrefix::Smart, 32768).is_err());
+    /// assert_eq!(wtr.len(), 5);
+    /// ```
+    #[inline]
+    fn write_length(&mut self, prefix: LengthPrefix, len: usize) -> Result<()> {
+        let err = || OsrsError::Other(format!("Failed writing length, value is {}", len));
+        match prefix {
+            LengthPrefix::U8 => self.write_u8(u8::try_from(len).map_err(|_| err())?),
+            LengthPrefix::U16 => self.write_u16(u16::try_from(len).map_err(|_| err())?),
+            LengthPrefix::Smart => self.write_u16_smart(u16::try_from(len).map_err(|_| err())?),
+        }
+    }
+
+    /// Writes a length prefixed table of CP1252 string key-value pairs to the writer.
+    ///
+    /// A length that does not fit the prefix, or a character with no CP1252 byte, is an error and nothing is written.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{LengthPrefix, ReadExt, WriteExt};
+    ///
+    /// let map = vec![("hi".to_owned(), "yo".to_owned()), ("a".to_owned(), "bc".to_owned())];
+    /// let mut wtr = Vec::new();
+    /// wtr.write_string_map(LengthPrefix::Smart, &map).unwrap();
+    /// assert_eq!(wtr, vec![2, 104, 105, 0, 121, 111, 0, 97, 0, 98, 99, 0]);
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// assert_eq!(rdr.read_string_map(LengthPrefix::Smart).unwrap(), map);
+    /// ```
+    ///
+    /// ```rust
+    /// use osrs_bytes::{LengthPrefix, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// assert!(wtr.write_string_map(LengthPrefix::U8, &[("ok", "fine"), ("bad", "\u{2603}")]).is_err());
+    /// assert!(wtr.is_empty());
+    /// ```
+    #[inline]
+    fn write_string_map<K: AsRef<str>, V: AsRef<str>>(
+        &mut self,
+        prefix: LengthPrefix,
+        map: &[(K, V)],
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        buf.write_length(prefix, map.len())?;
+        for (key, value) in map {
+            buf.write_string_cp1252(key)?;
+            buf.write_string_cp1252(value)?;
+        }
+        Ok(self.write_all(&buf)?)
+    }
+
+    /// Write bytes reversed with add to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let wtr1 = vec![1, 2, 3];
+    ///
+    /// let mut wtr2 = Vec::new();
+    /// wtr2.write_bytes_reversed_add(&wtr1);
+    /// assert_eq!(wtr2[0], 131);
+    /// assert_eq!(wtr2[1], 130);
+    /// assert_eq!(wtr2[2], 129);
+    /// ```
+    ///
+    #[inline]
+    fn write_bytes_reversed_add(&mut self, buf: &[u8]) -> Result<()> {
+        for b in buf.iter().rev() {
+            self.write_u8_add(*b)?;
+        }
+        Ok(())
+    }
+
+    /// Writes bytes with add to the writer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_bytes_add(&[1, 2, 3, 200]).unwrap();
+    /// assert_eq!(wtr, vec![129, 130, 131, 72]);
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// let mut buf = [0; 4];
+    /// rdr.read_bytes_add(&mut buf).unwrap();
+    /// assert_eq!(buf, [1, 2, 3, 200]);
+    /// ```
+    #[inline]
+    fn write_bytes_add(&mut self, buf: &[u8]) -> Result<()> {
+        for b in buf {
+            self.write_u8_add(*b)?;
+        }
+        Ok(())
+    }
+
+    /// Writes a loc shape and rotation packed into a single byte to the writer.
+    ///
+    /// The shape is stored in the upper six bits and the rotation in the lower two bits, i.e. `shape << 2 | rotation`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use osrs_bytes::WriteExt;
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_loc_shape(10, 1).unwrap();
+    /// wtr.write_loc_shape(22, 0).unwrap();
+    /// wtr.write_loc_shape(63, 3).unwrap();
+    /// assert_eq!(wtr, vec![41, 88, 255]);
+    /// ```
     ///
-    /// # Examples
+    /// A shape above 63 or a rotation above 3 does not fit and is an error.
     ///
     /// ```rust
     /// use osrs_bytes::WriteExt;
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_i8_add(42).unwrap();
-    /// assert_eq!(wtr[0], 170);
+    /// assert!(wtr.write_loc_shape(64, 0).is_err());
+    /// assert!(wtr.write_loc_shape(0, 4).is_err());
+    /// assert!(wtr.is_empty());
     /// ```
     #[inline]
-    fn write_i8_add(&mut self, n: i8) -> Result<()> {
-        self.write_u8_add(n as u8)
+    fn write_loc_shape(&mut self, shape: u8, rotation: u8) -> Result<()> {
+        if shape > 63 || rotation > 3 {
+            return Err(OsrsError::Other(format!(
+                "Failed writing loc shape, shape is {} and rotation is {}",
+                shape, rotation
+            )));
+        }
+        self.write_u8(shape << 2 | rotation)
     }
 
-    /// Writes a negated byte to the writer.
+    /// Writes an unsigned dword as a big smart to the writer.
+    ///
+    /// The value is written in the shortest of the one, two and four byte forms, see [`ReadExt::read_u32_big_smart`]. The maximum value is 0x3FFFFFFF.
     ///
     /// # Examples
     ///
@@ -1055,48 +5034,40 @@ pub trait WriteExt: Write {
     /// use osrs_bytes::WriteExt;
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_i8_neg(55).unwrap();
-    /// assert_eq!(wtr[0], 201);
+    /// wtr.write_u32_big_smart(0x3F).unwrap();
+    /// wtr.write_u32_big_smart(0x40).unwrap();
+    /// assert_eq!(wtr, vec![0x3F, 0x40, 0x40]);
     /// ```
-    #[inline]
-    fn write_i8_neg(&mut self, n: i8) -> Result<()> {
-        self.write_u8_neg(n as u8)
-    }
-
-    /// Writes a bool to the writer.
-    ///
-    /// Examples
     ///
     /// ```rust
     /// use osrs_bytes::WriteExt;
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_bool(true).unwrap();
-    /// assert_eq!(wtr[0], 1);
+    /// wtr.write_u32_big_smart(0x3FFF).unwrap();
+    /// wtr.write_u32_big_smart(0x4000).unwrap();
+    /// assert_eq!(wtr, vec![0x7F, 0xFF, 0x80, 0x00, 0x40, 0x00]);
     /// ```
-    #[inline]
-    fn write_bool(&mut self, b: bool) -> Result<()> {
-        self.write_all(&[b as u8])
-    }
-
-    /// Writes an unsigned short to the writer.
-    ///
-    /// # Examples
     ///
     /// ```rust
     /// use osrs_bytes::WriteExt;
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_u16(20065).unwrap();
-    /// assert_eq!(wtr[0], 78);
-    /// assert_eq!(wtr[1], 97);
+    /// wtr.write_u32_big_smart(0x3FFFFFFF).unwrap();
+    /// assert_eq!(wtr, vec![0xBF, 0xFF, 0xFF, 0xFF]);
+    /// assert!(wtr.write_u32_big_smart(0x40000000).is_err());
+    /// assert_eq!(wtr.len(), 4);
     /// ```
     #[inline]
-    fn write_u16(&mut self, n: u16) -> Result<()> {
-        self.write_all(&n.to_be_bytes())
+    fn write_u32_big_smart(&mut self, n: u32) -> Result<()> {
+        match n {
+            0..=0x3F => self.write_u8(n as u8),
+            0x40..=0x3FFF => self.write_u16(n as u16 | 0x4000),
+            0x4000..=0x3FFFFFFF => self.write_u32(n | 0x80000000),
+            _ => Err(OsrsError::SmartOutOfRange(n.into())),
+        }
     }
 
-    /// Writes an unsigned short as a little endian to the writer.
+    /// Writes bytes XORed with the key repeated cyclically to the writer.
     ///
     /// # Examples
     ///
@@ -1104,55 +5075,69 @@ pub trait WriteExt: Write {
     /// use osrs_bytes::WriteExt;
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_u16_le(29543).unwrap();
-    /// assert_eq!(wtr[0], 103);
-    /// assert_eq!(wtr[1], 115);
+    /// wtr.write_bytes_xor(&[1, 2, 3], &[0xFF, 0x0F]).unwrap();
+    /// assert_eq!(wtr, vec![254, 13, 252]);
     /// ```
-    ///
     #[inline]
-    fn write_u16_le(&mut self, n: u16) -> Result<()> {
-        self.write_all(&n.to_le_bytes())
+    fn write_bytes_xor(&mut self, buf: &[u8], key: &[u8]) -> Result<()> {
+        if key.is_empty() {
+            return Err(OsrsError::Other(
+                "Failed writing xor bytes, key is empty".to_owned(),
+            ));
+        }
+        let xored: Vec<u8> = buf
+            .iter()
+            .zip(key.iter().cycle())
+            .map(|(b, k)| b ^ k)
+            .collect();
+        Ok(self.write_all(&xored)?)
     }
 
-    /// Writes an unsigned short smart to the writer.
+    /// Writes an extended count to the writer.
     ///
-    /// # Examples
+    /// Counts below 255 are written as a single unsigned byte, anything else as the byte 255 followed by the count as a big endian u32.
     ///
-    /// Writing a value lesser than or equal to 127 makes it write out a single unsigned byte.
+    /// # Examples
     ///
     /// ```rust
     /// use osrs_bytes::WriteExt;
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_u16_smart(65).unwrap();
-    /// assert_eq!(wtr[0], 65);
-    /// assert!(wtr.get(1).is_none());
+    /// wtr.write_extended_count(42).unwrap();
+    /// wtr.write_extended_count(100000).unwrap();
+    /// assert_eq!(wtr, vec![42, 255, 0, 1, 134, 160]);
     /// ```
     ///
-    /// Writing a value greater than 127 will make it write out two unsigned bytes.
+    /// A count of exactly 255 already needs the extended form, and reads back through [`ReadExt::read_extended_count`].
     ///
     /// ```rust
-    /// use osrs_bytes::WriteExt;
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_u16_smart(986).unwrap();
-    /// assert_eq!(wtr[0], 131);
-    /// assert_eq!(wtr[1], 218);
+    /// for count in [0, 254, 255, 256, u32::MAX] {
+    ///     wtr.write_extended_count(count).unwrap();
+    /// }
+    /// assert_eq!(&wtr[..7], &[0, 254, 255, 0, 0, 0, 255]);
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// for count in [0, 254, 255, 256, u32::MAX] {
+    ///     assert_eq!(rdr.read_extended_count().unwrap(), count);
+    /// }
     /// ```
-    ///
     #[inline]
-    fn write_u16_smart(&mut self, n: u16) -> Result<()> {
-        match n {
-            0..=127 => self.write_u8(n as u8),
-            128..=32767 => self.write_u16(n + 32768),
-            _ => Err(Error::new(
-                ErrorKind::Other,
-                format!("Failed writing smart, value is {}", n),
-            )),
+    fn write_extended_count(&mut self, count: u32) -> Result<()> {
+        if count < 255 {
+            self.write_u8(count as u8)
+        } else {
+            self.write_u8(255)?;
+            self.write_u32(count)
         }
     }
 
-    /// Writes a signed short to the writer.
+    /// Writes a sub-tile coordinate pair to the writer.
+    ///
+    /// Each coordinate is rounded to the nearest 1/128 of a tile and written as a signed short, see [`ReadExt::read_subtile_coord`].
     ///
     /// # Examples
     ///
@@ -1160,52 +5145,99 @@ pub trait WriteExt: Write {
     /// use osrs_bytes::WriteExt;
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_i16(-14632).unwrap();
-    /// assert_eq!(wtr[0], 198);
-    /// assert_eq!(wtr[1], 216);
+    /// wtr.write_subtile_coord(3.0, -0.5).unwrap();
+    /// assert_eq!(wtr, vec![1, 128, 255, 192]);
     /// ```
-    #[inline]
-    fn write_i16(&mut self, n: i16) -> Result<()> {
-        self.write_u16(n as u16)
-    }
-
-    /// Writes a signed short as little endian to the writer.
     ///
-    /// # Examples
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// wtr.write_subtile_coord(12.3, -4.56).unwrap();
+    ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// let (x, y) = rdr.read_subtile_coord().unwrap();
+    /// assert!((x - 12.3).abs() <= 1.0 / 256.0);
+    /// assert!((y + 4.56).abs() <= 1.0 / 256.0);
+    /// ```
+    ///
+    /// A coordinate outside the range of a signed short is an error.
     ///
     /// ```rust
     /// use osrs_bytes::WriteExt;
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_i16_le(-7654).unwrap();
-    /// assert_eq!(wtr[0], 26);
-    /// assert_eq!(wtr[1], 226);
+    /// assert!(wtr.write_subtile_coord(256.0, 0.0).is_err());
+    /// assert!(wtr.is_empty());
     /// ```
     #[inline]
-    fn write_i16_le(&mut self, n: i16) -> Result<()> {
-        self.write_u16_le(n as u16)
+    fn write_subtile_coord(&mut self, x: f32, y: f32) -> Result<()> {
+        let fixed = |n: f32| {
+            let scaled = (n * 128.0).round();
+            if scaled >= i16::MIN as f32 && scaled <= i16::MAX as f32 {
+                Ok(scaled as i16)
+            } else {
+                Err(OsrsError::Other(format!(
+                    "Failed writing sub-tile coordinate, value is {}",
+                    n
+                )))
+            }
+        };
+        let (x, y) = (fixed(x)?, fixed(y)?);
+        self.write_i16(x)?;
+        self.write_i16(y)
     }
 
-    /// Writes a signed short add to the writer.
+    /// Writes a signed integer of 1 to 8 bytes to the writer.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use osrs_bytes::WriteExt;
+    /// use osrs_bytes::{Endianness, WriteExt};
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_i16_add(-9867).unwrap();
-    /// assert_eq!(wtr[0], 217);
-    /// assert_eq!(wtr[1], 245);
+    /// wtr.write_int_signed(-8388608, 3, Endianness::Big).unwrap();
+    /// wtr.write_int_signed(8388607, 3, Endianness::Little).unwrap();
+    /// assert_eq!(wtr, vec![128, 0, 0, 255, 255, 127]);
     /// ```
     ///
+    /// A value that does not fit in the given width is an error.
+    ///
+    /// ```rust
+    /// use osrs_bytes::{Endianness, WriteExt};
+    ///
+    /// let mut wtr = Vec::new();
+    /// assert!(wtr.write_int_signed(8388608, 3, Endianness::Big).is_err());
+    /// assert!(wtr.write_int_signed(-8388609, 3, Endianness::Big).is_err());
+    /// assert!(wtr.is_empty());
+    /// ```
     #[inline]
-    fn write_i16_add(&mut self, n: i16) -> Result<()> {
-        self.write_i8((n >> 8) as i8)?;
-        self.write_i8((n + 128) as i8)
+    fn write_int_signed(&mut self, n: i64, bytes: usize, endian: Endianness) -> Result<()> {
+        if !(1..=8).contains(&bytes) {
+            return Err(OsrsError::Other(format!(
+                "Failed writing signed integer, width is {}",
+                bytes
+            )));
+        }
+        let shift = 64 - 8 * bytes as u32;
+        if (n << shift) >> shift != n {
+            return Err(OsrsError::Other(format!(
+                "Failed writing signed integer, value is {} for width {}",
+                n, bytes
+            )));
+        }
+        let mut buf = n.to_be_bytes();
+        let buf = &mut buf[8 - bytes..];
+        if endian == Endianness::Little {
+            buf.reverse();
+        }
+        Ok(self.write_all(buf)?)
     }
 
-    /// Writes a signed short add as a little endian to the writer.
+    /// Writes bools packed into bits, most significant bit first, to the writer.
+    ///
+    /// The last byte is padded with zero bits.
     ///
     /// # Examples
     ///
@@ -1213,234 +5245,304 @@ pub trait WriteExt: Write {
     /// use osrs_bytes::WriteExt;
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_i16_add_le(-12632).unwrap();
-    /// assert_eq!(wtr[0], 40);
-    /// assert_eq!(wtr[1], 206);
+    /// wtr.write_bool_array(&[true, false, true, false, false, false, false, true, false, true]).unwrap();
+    /// assert_eq!(wtr, vec![0b1010_0001, 0b0100_0000]);
     /// ```
-    ///
     #[inline]
-    fn write_i16_add_le(&mut self, n: i16) -> Result<()> {
-        self.write_i8((n + 128) as i8)?;
-        self.write_i8((n >> 8) as i8)
+    fn write_bool_array(&mut self, bools: &[bool]) -> Result<()> {
+        let mut buf = vec![0u8; bools.len().div_ceil(8)];
+        for (i, _) in bools.iter().enumerate().filter(|(_, b)| **b) {
+            buf[i / 8] |= 0x80 >> (i % 8);
+        }
+        Ok(self.write_all(&buf)?)
     }
 
-    /// Writes an unsigned dword to the writer.
+    /// Writes an item container to the writer.
+    ///
+    /// Each slot is written as the item id plus one as a smart, or 0 for an empty slot, followed by the count as an extended count, see [`ReadExt::read_item_container`].
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use osrs_bytes::WriteExt;
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let items = vec![Some((1250, 1)), None, Some((4067, 1000)), None];
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_u32(98571).unwrap();
-    /// assert_eq!(wtr[0], 0);
-    /// assert_eq!(wtr[1], 1);
-    /// assert_eq!(wtr[2], 129);
-    /// assert_eq!(wtr[3], 11);
-    /// ```
+    /// wtr.write_item_container(&items).unwrap();
+    /// assert_eq!(&wtr[..5], &[0x84, 0xE3, 1, 0, 0]);
     ///
-    #[inline]
-    fn write_u32(&mut self, n: u32) -> Result<()> {
-        self.write_all(&n.to_be_bytes())
-    }
-
-    /// Writes am unsigned integer as little endian to the writer.
+    /// let mut rdr = Cursor::new(wtr);
+    /// assert_eq!(rdr.read_item_container(items.len()).unwrap(), items);
+    /// ```
     ///
-    /// # Examples
+    /// An id that does not fit in a smart is an error, and nothing is written.
     ///
     /// ```rust
     /// use osrs_bytes::WriteExt;
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_u32_le(26904).unwrap();
-    /// assert_eq!(wtr[0], 24);
-    /// assert_eq!(wtr[1], 105);
-    /// assert_eq!(wtr[2], 0);
-    /// assert_eq!(wtr[3], 0);
+    /// assert!(wtr.write_item_container(&[Some((1, 1)), Some((32767, 1))]).is_err());
+    /// assert!(wtr.is_empty());
     /// ```
-    ///
     #[inline]
-    fn write_u32_le(&mut self, n: u32) -> Result<()> {
-        self.write_all(&n.to_le_bytes())
+    fn write_item_container(&mut self, items: &[Option<(u32, u32)>]) -> Result<()> {
+        if let Some((id, _)) = items.iter().flatten().find(|(id, _)| *id >= 32767) {
+            return Err(OsrsError::Other(format!(
+                "Failed writing item container, id is {}",
+                id
+            )));
+        }
+        for item in items {
+            match item {
+                Some((id, count)) => {
+                    self.write_u16_smart(*id as u16 + 1)?;
+                    self.write_extended_count(*count)?;
+                }
+                None => {
+                    self.write_u16_smart(0)?;
+                    self.write_extended_count(0)?;
+                }
+            }
+        }
+        Ok(())
     }
 
-    /// Writes a signed dword to the writer.
+    /// Writes a delta encoded array to the writer.
+    ///
+    /// The first value is written as an unsigned short and every following value as a signed smart delta from the previous one, see [`ReadExt::read_delta_u16_array`].
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use osrs_bytes::WriteExt;
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
+    ///
+    /// let values = vec![1000, 1200, 1195, 1195, 17578];
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_i32(-131045).unwrap();
-    /// assert_eq!(wtr[0], 255);
-    /// assert_eq!(wtr[1], 254);
-    /// assert_eq!(wtr[2], 0);
-    /// assert_eq!(wtr[3], 27);
-    /// ```
+    /// wtr.write_delta_u16_array(&values).unwrap();
+    /// assert_eq!(&wtr[..5], &[3, 232, 0xC0, 0xC8, 0x3B]);
     ///
-    #[inline]
-    fn write_i32(&mut self, n: i32) -> Result<()> {
-        self.write_u32(n as u32)
-    }
-
-    /// Writes a signed integer as little endian to the writer.
+    /// let mut rdr = Cursor::new(wtr);
+    /// assert_eq!(rdr.read_delta_u16_array(values.len()).unwrap(), values);
+    /// ```
     ///
-    /// # Examples
+    /// A first value above 65535 or a delta outside of -16384 to 16383 is an error, and nothing is written.
     ///
     /// ```rust
     /// use osrs_bytes::WriteExt;
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_i32_le(18879).unwrap();
-    /// assert_eq!(wtr[0], 191);
-    /// assert_eq!(wtr[1], 73);
-    /// assert_eq!(wtr[2], 0);
-    /// assert_eq!(wtr[3], 0);
+    /// assert!(wtr.write_delta_u16_array(&[0, 16384]).is_err());
+    /// assert!(wtr.write_delta_u16_array(&[65536]).is_err());
+    /// assert!(wtr.write_delta_u16_array(&[65535, 65536]).is_err());
+    /// assert!(wtr.is_empty());
     /// ```
-    ///
     #[inline]
-    fn write_i32_le(&mut self, n: i32) -> Result<()> {
-        self.write_u32_le(n as u32)
+    fn write_delta_u16_array(&mut self, values: &[u32]) -> Result<()> {
+        let Some((&first, rest)) = values.split_first() else {
+            return Ok(());
+        };
+        let first = u16::try_from(first).map_err(|_| {
+            OsrsError::Other(format!(
+                "Failed writing delta array, first value is {}",
+                first
+            ))
+        })?;
+        if let Some(value) = rest.iter().find(|v| **v > u16::MAX as u32) {
+            return Err(OsrsError::Other(format!(
+                "Failed writing delta array, value is {}",
+                value
+            )));
+        }
+        let mut deltas = Vec::with_capacity(rest.len());
+        for pair in values.windows(2) {
+            let delta = pair[1] as i64 - pair[0] as i64;
+            if !(-16384..=16383).contains(&delta) {
+                return Err(OsrsError::Other(format!(
+                    "Failed writing delta array, delta is {}",
+                    delta
+                )));
+            }
+            deltas.push(delta as i16);
+        }
+        self.write_u16(first)?;
+        for delta in deltas {
+            self.write_i16_smart(delta)?;
+        }
+        Ok(())
     }
 
-    /// Writes a signed dword as a middle endian to the writer.
+    /// Enciphers a block of two dwords with XTEA and writes it as big endian to the writer.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use osrs_bytes::WriteExt;
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{ReadExt, WriteExt};
     ///
+    /// let key = [0x00010203, 0x04050607, 0x08090A0B, 0x0C0D0E0F];
     /// let mut wtr = Vec::new();
-    /// wtr.write_i32_me(-98231).unwrap();
-    /// assert_eq!(wtr[0], 254);
-    /// assert_eq!(wtr[1], 255);
-    /// assert_eq!(wtr[2], 73);
-    /// assert_eq!(wtr[3], 128);
-    /// ```
+    /// wtr.write_xtea_block([0x41424344, 0x45464748], &key).unwrap();
+    /// assert_eq!(wtr, vec![0x49, 0x7D, 0xF3, 0xD0, 0x72, 0x61, 0x2C, 0xB5]);
     ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// assert_eq!(rdr.read_xtea_block(&key).unwrap(), [0x41424344, 0x45464748]);
+    /// ```
     #[inline]
-    fn write_i32_me(&mut self, n: i32) -> Result<()> {
-        self.write_i16_le((n >> 16) as i16)?;
-        self.write_i16_le(n as i16)
+    fn write_xtea_block(&mut self, block: [u32; 2], key: &[u32; 4]) -> Result<()> {
+        let [v0, v1] = xtea::encipher(block, key);
+        self.write_u32(v0)?;
+        self.write_u32(v1)
     }
 
-    /// Writes a signed dword as an inversed middle endian to the writer.
+    /// Writes a packet opcode to the writer, adding the next offset of the ISAAC generator.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use osrs_bytes::WriteExt;
+    /// use osrs_bytes::{Isaac, WriteExt};
     ///
+    /// let mut isaac = Isaac::new([0; 4]);
+    /// let offset = Isaac::new([0; 4]).next_opcode_offset();
     /// let mut wtr = Vec::new();
-    /// wtr.write_i32_ime(-98231).unwrap();
-    /// assert_eq!(wtr[0], 128);
-    /// assert_eq!(wtr[1], 73);
-    /// assert_eq!(wtr[2], 255);
-    /// assert_eq!(wtr[3], 254);
+    /// wtr.write_packet_opcode(35, &mut isaac).unwrap();
+    /// assert_eq!(wtr, vec![35u8.wrapping_add(offset)]);
     /// ```
-    ///
     #[inline]
-    fn write_i32_ime(&mut self, n: i32) -> Result<()> {
-        self.write_i16(n as i16)?;
-        self.write_i16((n >> 16) as i16)
+    fn write_packet_opcode(&mut self, opcode: u8, isaac: &mut Isaac) -> Result<()> {
+        self.write_u8(opcode.wrapping_add(isaac.next_opcode_offset()))
     }
 
-    /// Writes an unsigned qword to the writer.
+    /// Writes a whole game packet, the opcode as [`WriteExt::write_packet_opcode`], the size prefix for `size` and the body.
+    ///
+    /// A body that does not match a fixed size, or is too long for its size prefix, is an error and nothing is written. The ISAAC generator is not advanced either.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use osrs_bytes::WriteExt;
+    /// use osrs_bytes::{Isaac, PacketSize, WriteExt};
     ///
+    /// let offset = Isaac::new([0; 4]).next_opcode_offset();
     /// let mut wtr = Vec::new();
-    /// wtr.write_u64(8589934592).unwrap();
-    /// assert_eq!(wtr[0], 0);
-    /// assert_eq!(wtr[1], 0);
-    /// assert_eq!(wtr[2], 0);
-    /// assert_eq!(wtr[3], 2);
-    /// assert_eq!(wtr[4], 0);
-    /// assert_eq!(wtr[5], 0);
-    /// assert_eq!(wtr[6], 0);
-    /// assert_eq!(wtr[7], 0);
+    /// wtr.write_game_packet(35, PacketSize::VarByte, &mut Isaac::new([0; 4]), &[1, 2]).unwrap();
+    /// assert_eq!(wtr, vec![35u8.wrapping_add(offset), 2, 1, 2]);
     /// ```
     ///
+    /// ```rust
+    /// use osrs_bytes::{Isaac, PacketSize, WriteExt};
+    ///
+    /// let mut isaac = Isaac::new([0; 4]);
+    /// let mut wtr = Vec::new();
+    /// assert!(wtr.write_game_packet(1, PacketSize::Fixed(3), &mut isaac, &[1, 2]).is_err());
+    /// assert!(wtr.write_game_packet(1, PacketSize::VarByte, &mut isaac, &[0; 256]).is_err());
+    /// assert!(wtr.write_game_packet(1, PacketSize::VarShort, &mut isaac, &vec![0; 65536]).is_err());
+    /// assert!(wtr.is_empty());
+    /// assert_eq!(isaac.next_u32(), Isaac::new([0; 4]).next_u32());
+    /// ```
     #[inline]
-    fn write_u64(&mut self, n: u64) -> Result<()> {
-        self.write_all(&n.to_be_bytes())
+    fn write_game_packet(
+        &mut self,
+        opcode: u8,
+        size: PacketSize,
+        isaac: &mut Isaac,
+        body: &[u8],
+    ) -> Result<()> {
+        let fits = match size {
+            PacketSize::Fixed(len) => body.len() == len,
+            PacketSize::VarByte => body.len() <= u8::MAX as usize,
+            PacketSize::VarShort => body.len() <= u16::MAX as usize,
+        };
+        if !fits {
+            return Err(OsrsError::Other(format!(
+                "Failed writing packet {}, body of {} bytes does not fit {:?}",
+                opcode,
+                body.len(),
+                size
+            )));
+        }
+
+        self.write_packet_opcode(opcode, isaac)?;
+        match size {
+            PacketSize::Fixed(_) => {}
+            PacketSize::VarByte => self.write_u8(body.len() as u8)?,
+            PacketSize::VarShort => self.write_u16(body.len() as u16)?,
+        }
+        Ok(self.write_all(body)?)
     }
 
-    /// Writes a signed qword to the writer.
+    /// Writes an unsigned short in the given byte order to the writer.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use osrs_bytes::WriteExt;
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{Endianness, ReadExt, WriteExt};
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_i64(-8589934592).unwrap();
-    /// assert_eq!(wtr[0], 255);
-    /// assert_eq!(wtr[1], 255);
-    /// assert_eq!(wtr[2], 255);
-    /// assert_eq!(wtr[3], 254);
-    /// assert_eq!(wtr[4], 0);
-    /// assert_eq!(wtr[5], 0);
-    /// assert_eq!(wtr[6], 0);
-    /// assert_eq!(wtr[7], 0);
-    /// ```
+    /// wtr.write_u16_endian(Endianness::Big, 0x0102).unwrap();
+    /// wtr.write_u16_endian(Endianness::Little, 0x0102).unwrap();
     ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// assert_eq!(rdr.read_u16().unwrap(), 0x0102);
+    /// assert_eq!(rdr.read_u16_le().unwrap(), 0x0102);
+    /// ```
     #[inline]
-    fn write_i64(&mut self, n: i64) -> Result<()> {
-        self.write_u64(n as u64)
+    fn write_u16_endian(&mut self, endian: Endianness, n: u16) -> Result<()> {
+        match endian {
+            Endianness::Big => self.write_u16(n),
+            Endianness::Little => self.write_u16_le(n),
+        }
     }
 
-    /// Writes a CP1252 string to the writer.
+    /// Writes an unsigned dword in the given byte order to the writer.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use osrs_bytes::WriteExt;
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{Endianness, ReadExt, WriteExt};
     ///
     /// let mut wtr = Vec::new();
-    /// wtr.write_string_cp1252("hello").unwrap();
-    /// assert_eq!(wtr[0], 104);
-    /// assert_eq!(wtr[1], 101);
-    /// assert_eq!(wtr[2], 108);
-    /// assert_eq!(wtr[3], 108);
-    /// assert_eq!(wtr[4], 111);
-    /// assert_eq!(wtr[5], 0);
-    /// ```
+    /// wtr.write_u32_endian(Endianness::Big, 0x01020304).unwrap();
+    /// wtr.write_u32_endian(Endianness::Little, 0x01020304).unwrap();
     ///
+    /// let mut rdr = Cursor::new(wtr);
+    /// assert_eq!(rdr.read_u32().unwrap(), 0x01020304);
+    /// assert_eq!(rdr.read_u32_le().unwrap(), 0x01020304);
+    /// ```
     #[inline]
-    fn write_string_cp1252<T: AsRef<str>>(&mut self, s: T) -> Result<()> {
-        for b in s.as_ref().as_bytes() {
-            self.write_u8(*b)?;
+    fn write_u32_endian(&mut self, endian: Endianness, n: u32) -> Result<()> {
+        match endian {
+            Endianness::Big => self.write_u32(n),
+            Endianness::Little => self.write_u32_le(n),
         }
-        self.write_i8(0)
     }
 
-    /// Write bytes reversed with add to the writer.
+    /// Writes an unsigned qword in the given byte order to the writer.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use osrs_bytes::WriteExt;
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{Endianness, ReadExt, WriteExt};
     ///
-    /// let wtr1 = vec![1, 2, 3];
+    /// let mut wtr = Vec::new();
+    /// wtr.write_u64_endian(Endianness::Big, 0x0102030405060708).unwrap();
+    /// wtr.write_u64_endian(Endianness::Little, 0x0102030405060708).unwrap();
     ///
-    /// let mut wtr2 = Vec::new();
-    /// wtr2.write_bytes_reversed_add(&wtr1);
-    /// assert_eq!(wtr2[0], 131);
-    /// assert_eq!(wtr2[1], 130);
-    /// assert_eq!(wtr2[2], 129);
+    /// let mut rdr = Cursor::new(wtr);
+    /// assert_eq!(rdr.read_u64().unwrap(), 0x0102030405060708);
+    /// assert_eq!(rdr.read_u64_le().unwrap(), 0x0102030405060708);
     /// ```
-    ///
     #[inline]
-    fn write_bytes_reversed_add(&mut self, buf: &[u8]) -> Result<()> {
-        for b in buf.iter().rev() {
-            self.write_u8_add(*b)?;
+    fn write_u64_endian(&mut self, endian: Endianness, n: u64) -> Result<()> {
+        match endian {
+            Endianness::Big => self.write_u64(n),
+            Endianness::Little => self.write_u64_le(n),
         }
-        Ok(())
     }
 }
 
@@ -0,0 +1,157 @@
+use crate::{OsrsError, ReadExt, Result, Tristate, WriteExt};
+
+struct Vector {
+    name: &'static str,
+    bytes: &'static [u8],
+    write: fn(&mut Vec<u8>) -> Result<()>,
+    read: fn(&mut &[u8]) -> Result<bool>,
+}
+
+macro_rules! vector {
+    ($read:ident, $write:ident, $value:expr, $bytes:expr) => {
+        Vector {
+            name: stringify!($read),
+            bytes: &$bytes,
+            write: |wtr| wtr.$write($value),
+            read: |rdr| Ok(rdr.$read()? == $value),
+        }
+    };
+}
+
+const VECTORS: &[Vector] = &[
+    vector!(read_u8, write_u8, 42, [42]),
+    vector!(read_u8_add, write_u8_add, 42, [170]),
+    vector!(read_u8_neg, write_u8_neg, 42, [214]),
+    vector!(read_u8_sub, write_u8_sub, 42, [86]),
+    vector!(read_i8, write_i8, -67, [189]),
+    vector!(read_i8_add, write_i8_add, -3, [125]),
+    vector!(read_i8_neg, write_i8_neg, 55, [201]),
+    vector!(read_i8_sub, write_i8_sub, 99, [29]),
+    vector!(read_bool, write_bool, true, [1]),
+    vector!(read_tristate, write_tristate, Tristate::Inherit, [2]),
+    vector!(read_u16, write_u16, 20065, [78, 97]),
+    vector!(read_u16_le, write_u16_le, 29543, [103, 115]),
+    vector!(read_u16_smart, write_u16_smart, 65, [65]),
+    vector!(read_u16_smart, write_u16_smart, 986, [131, 218]),
+    vector!(read_i16_smart, write_i16_smart, -64, [0]),
+    vector!(read_i16_smart, write_i16_smart, 64, [0xC0, 0x40]),
+    vector!(read_i16, write_i16, -14632, [198, 216]),
+    vector!(read_i16_le, write_i16_le, -7654, [26, 226]),
+    vector!(read_u16_add, write_u16_add, 25346, [99, 130]),
+    vector!(read_u16_add_le, write_u16_add_le, 17113, [89, 66]),
+    vector!(read_i16_add, write_i16_add, -9867, [217, 245]),
+    vector!(read_i16_add_le, write_i16_add_le, -12632, [40, 206]),
+    vector!(read_u24, write_u24, 66051, [1, 2, 3]),
+    vector!(read_u24_le, write_u24_le, 66051, [3, 2, 1]),
+    vector!(read_i24, write_i24, -2, [255, 255, 254]),
+    vector!(read_i24_le, write_i24_le, -2, [254, 255, 255]),
+    vector!(read_u32, write_u32, 98571, [0, 1, 129, 11]),
+    vector!(read_u32_smart, write_u32_smart, 32767, [0x7F, 0xFF]),
+    vector!(
+        read_u32_smart,
+        write_u32_smart,
+        32768,
+        [0x80, 0x00, 0x80, 0x00]
+    ),
+    vector!(read_u32_big_smart, write_u32_big_smart, 0x3F, [0x3F]),
+    vector!(
+        read_u32_big_smart,
+        write_u32_big_smart,
+        0x3FFF,
+        [0x7F, 0xFF]
+    ),
+    vector!(
+        read_u32_big_smart,
+        write_u32_big_smart,
+        0x4000,
+        [0x80, 0x00, 0x40, 0x00]
+    ),
+    vector!(read_extended_count, write_extended_count, 42, [42]),
+    vector!(
+        read_extended_count,
+        write_extended_count,
+        100000,
+        [255, 0, 1, 134, 160]
+    ),
+    vector!(read_u32_le, write_u32_le, 26904, [24, 105, 0, 0]),
+    vector!(read_u32_me, write_u32_me, 83964169, [1, 5, 9, 49]),
+    vector!(read_u32_ime, write_u32_ime, 9764864, [0, 0, 0, 149]),
+    vector!(read_i32, write_i32, -131045, [255, 254, 0, 27]),
+    vector!(read_i32_le, write_i32_le, 18879, [191, 73, 0, 0]),
+    vector!(read_i32_me, write_i32_me, -98231, [254, 255, 73, 128]),
+    vector!(read_i32_ime, write_i32_ime, -98231, [128, 73, 255, 254]),
+    vector!(read_u64, write_u64, 8589934592, [0, 0, 0, 2, 0, 0, 0, 0]),
+    vector!(
+        read_i64,
+        write_i64,
+        -8589934592,
+        [255, 255, 255, 254, 0, 0, 0, 0]
+    ),
+    vector!(
+        read_string_cp1252,
+        write_string_cp1252,
+        "hello",
+        [104, 101, 108, 108, 111, 0]
+    ),
+    vector!(
+        read_versioned_string,
+        write_versioned_string,
+        "hello",
+        [0, 104, 101, 108, 108, 111, 0]
+    ),
+];
+
+/// Round-trips every read and write pair against known byte vectors.
+///
+/// This is meant as a smoke test for dependent applications, for example when porting to a new platform. The error describes the first mismatch.
+///
+/// # Examples
+///
+/// ```rust
+/// osrs_bytes::selftest().unwrap();
+/// ```
+pub fn selftest() -> Result<()> {
+    run(VECTORS)
+}
+
+fn run(vectors: &[Vector]) -> Result<()> {
+    for v in vectors {
+        let mut wtr = Vec::new();
+        (v.write)(&mut wtr)?;
+        if wtr != v.bytes {
+            return Err(OsrsError::Other(format!(
+                "Selftest failed, {} wrote {:?}, expected {:?}",
+                v.name, wtr, v.bytes
+            )));
+        }
+
+        let mut rdr = v.bytes;
+        if !(v.read)(&mut rdr)? || !rdr.is_empty() {
+            return Err(OsrsError::Other(format!(
+                "Selftest failed, {} did not read back {:?}",
+                v.name, v.bytes
+            )));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selftest() {
+        assert!(selftest().is_ok());
+    }
+
+    #[test]
+    fn test_selftest_corrupted() {
+        let corrupted = [vector!(read_u16, write_u16, 20065, [78, 98])];
+        let err = run(&corrupted).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Selftest failed, read_u16 wrote [78, 97], expected [78, 98]"
+        );
+    }
+}
@@ -0,0 +1,317 @@
+//! Player, npc and region updates read in bit access mode.
+//!
+//! The layouts are those of the original Oldschool client, where each local entity has a movement entry, new players are added by index and dynamic regions are built from copied chunks. Directions are the client's 3-bit directions, from 0 for north-west to 7 for south-east.
+
+use crate::{BitReader, Result};
+use std::io::Read;
+
+/// The movement of a local player or npc since the last update.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Movement {
+    /// Did not move
+    None,
+    /// Walked one tile in the direction
+    Walk(u8),
+    /// Ran two tiles in the directions
+    Run(u8, u8),
+    /// Moved to the local coordinates, only sent for the player being updated
+    Teleport {
+        /// The plane
+        plane: u8,
+        /// The local x coordinate
+        x: u8,
+        /// The local y coordinate
+        y: u8,
+        /// Whether the queued path is discarded
+        clear_path: bool,
+    },
+    /// Left the local list, only sent for other entities
+    Remove,
+}
+
+/// A movement entry of a local player or npc.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MovementUpdate {
+    /// The movement
+    pub movement: Movement,
+    /// Whether an update block follows the bit section
+    pub update: bool,
+}
+
+/// A player added to the local list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AddedPlayer {
+    /// The player index
+    pub index: u16,
+    /// The x offset from the player being updated
+    pub dx: i8,
+    /// The y offset from the player being updated
+    pub dy: i8,
+    /// Whether the queued path is discarded
+    pub clear_path: bool,
+    /// Whether an update block follows the bit section
+    pub update: bool,
+}
+
+/// The bit section of a player update packet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlayerUpdateHeader {
+    /// The movement of the player being updated
+    pub local: MovementUpdate,
+    /// The movement of each other local player, in list order
+    pub others: Vec<MovementUpdate>,
+    /// The players added to the local list
+    pub added: Vec<AddedPlayer>,
+}
+
+/// A chunk of the static map copied into a dynamic region.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DynamicChunk {
+    /// The plane of the source chunk
+    pub plane: u8,
+    /// The x coordinate of the source chunk, in chunks
+    pub x: u16,
+    /// The y coordinate of the source chunk, in chunks
+    pub y: u16,
+    /// The number of quarter turns clockwise the chunk is rotated by
+    pub rotation: u8,
+}
+
+/// The chunks of a dynamic region, a 13 by 13 area of chunks on each of the four planes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynamicRegion {
+    /// The source chunk of each chunk, indexed by plane, then x, then y
+    pub chunks: [[[Option<DynamicChunk>; 13]; 13]; 4],
+}
+
+impl<R: Read> BitReader<R> {
+    /// Reads the bit section of a player update packet.
+    ///
+    /// The player being updated comes first: a bit of 0 means no movement and no update block, otherwise 2 bits select the movement type. Type 0 is an update block without movement, 1 a walk of a 3-bit direction, 2 a run of two 3-bit directions, each followed by the update bit, and 3 a teleport of a 2-bit plane, a clear-path bit, the update bit and the 7-bit local y and x.
+    ///
+    /// An 8-bit count of other local players follows, each with the same entry except that type 3 removes the player. Then come the added players, each an 11-bit index, the update bit, a clear-path bit and the 5-bit signed y and x offsets, ended by index 2047. Servers write that index before the update blocks, and this reader requires it.
+    ///
+    /// # Examples
+    ///
+    /// The player being updated walks north and another player stands still.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{BitReader, BitWriter};
+    /// use osrs_bytes::update::{Movement, MovementUpdate};
+    ///
+    /// let mut wtr = BitWriter::new(Vec::new());
+    /// for (value, count) in [(1, 1), (1, 2), (1, 3), (0, 1), (1, 8), (0, 1), (2047, 11)] {
+    ///     wtr.write_bits(value, count).unwrap();
+    /// }
+    /// let buf = wtr.finish().unwrap();
+    ///
+    /// let header = BitReader::new(Cursor::new(buf)).read_player_update_header().unwrap();
+    /// assert_eq!(header.local, MovementUpdate { movement: Movement::Walk(1), update: false });
+    /// assert_eq!(header.others, vec![MovementUpdate { movement: Movement::None, update: false }]);
+    /// assert!(header.added.is_empty());
+    /// ```
+    ///
+    /// The player being updated teleports, another player runs and is then removed, and a player is added south-west of the player being updated.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{BitReader, BitWriter};
+    /// use osrs_bytes::update::{AddedPlayer, Movement, MovementUpdate};
+    ///
+    /// let mut wtr = BitWriter::new(Vec::new());
+    /// let bits = [
+    ///     (1, 1), (3, 2), (1, 2), (1, 1), (1, 1), (52, 7), (49, 7),
+    ///     (2, 8), (1, 1), (2, 2), (4, 3), (4, 3), (1, 1), (1, 1), (3, 2),
+    ///     (17, 11), (1, 1), (0, 1), (0b11110, 5), (0b11101, 5),
+    ///     (2047, 11),
+    /// ];
+    /// for (value, count) in bits {
+    ///     wtr.write_bits(value, count).unwrap();
+    /// }
+    /// let buf = wtr.finish().unwrap();
+    ///
+    /// let header = BitReader::new(Cursor::new(buf)).read_player_update_header().unwrap();
+    /// assert_eq!(
+    ///     header.local.movement,
+    ///     Movement::Teleport { plane: 1, x: 49, y: 52, clear_path: true }
+    /// );
+    /// assert!(header.local.update);
+    /// assert_eq!(header.others[0], MovementUpdate { movement: Movement::Run(4, 4), update: true });
+    /// assert_eq!(header.others[1].movement, Movement::Remove);
+    /// assert_eq!(
+    ///     header.added,
+    ///     vec![AddedPlayer { index: 17, dx: -3, dy: -2, clear_path: false, update: true }]
+    /// );
+    /// ```
+    ///
+    /// Running out of bits before index 2047 is an error.
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::BitReader;
+    ///
+    /// let mut rdr = BitReader::new(Cursor::new(vec![0, 0]));
+    /// assert!(rdr.read_player_update_header().is_err());
+    /// ```
+    pub fn read_player_update_header(&mut self) -> Result<PlayerUpdateHeader> {
+        let local = self.read_movement_update(true)?;
+        let count = self.read_bits(8)? as usize;
+        let mut others = Vec::with_capacity(count);
+        for _ in 0..count {
+            others.push(self.read_movement_update(false)?);
+        }
+
+        let mut added = Vec::new();
+        loop {
+            let index = self.read_bits(11)? as u16;
+            if index == 2047 {
+                break;
+            }
+            let update = self.read_bits(1)? == 1;
+            let clear_path = self.read_bits(1)? == 1;
+            let dy = self.read_offset()?;
+            let dx = self.read_offset()?;
+            added.push(AddedPlayer {
+                index,
+                dx,
+                dy,
+                clear_path,
+                update,
+            });
+        }
+
+        Ok(PlayerUpdateHeader {
+            local,
+            others,
+            added,
+        })
+    }
+
+    /// Reads the movement entry of a local npc in an npc update packet.
+    ///
+    /// A bit of 0 means no movement and no update block, otherwise 2 bits select the movement type. Type 0 is an update block without movement, 1 a walk of a 3-bit direction and 2 a run of two 3-bit directions, each followed by the update bit, and 3 removes the npc. This is the same entry as the other players in [`BitReader::read_player_update_header`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{BitReader, BitWriter};
+    /// use osrs_bytes::update::{Movement, MovementUpdate};
+    ///
+    /// let mut wtr = BitWriter::new(Vec::new());
+    /// for (value, count) in [(1, 1), (1, 2), (6, 3), (1, 1), (1, 1), (3, 2), (0, 1)] {
+    ///     wtr.write_bits(value, count).unwrap();
+    /// }
+    /// let mut rdr = BitReader::new(Cursor::new(wtr.finish().unwrap()));
+    ///
+    /// assert_eq!(
+    ///     rdr.read_npc_movement().unwrap(),
+    ///     MovementUpdate { movement: Movement::Walk(6), update: true }
+    /// );
+    /// assert_eq!(rdr.read_npc_movement().unwrap().movement, Movement::Remove);
+    /// assert_eq!(rdr.read_npc_movement().unwrap().movement, Movement::None);
+    /// ```
+    pub fn read_npc_movement(&mut self) -> Result<MovementUpdate> {
+        self.read_movement_update(false)
+    }
+
+    /// Reads the chunk table of a dynamic region.
+    ///
+    /// Each chunk is a bit of 0 for an empty chunk, or a bit of 1 followed by a 26-bit reference to its source chunk, looping over the planes, then x, then y. The reference holds the plane in bits 24 and 25, the x in bits 14 to 23, the y in bits 3 to 13 and the rotation in bits 1 and 2.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::Cursor;
+    /// use osrs_bytes::{BitReader, BitWriter};
+    /// use osrs_bytes::update::DynamicChunk;
+    ///
+    /// let mut wtr = BitWriter::new(Vec::new());
+    /// for plane in 0..4 {
+    ///     for x in 0..13 {
+    ///         for y in 0..13 {
+    ///             if (plane, x, y) == (0, 6, 7) {
+    ///                 wtr.write_bits(1, 1).unwrap();
+    ///                 wtr.write_bits(1 << 24 | 402 << 14 | 440 << 3 | 3 << 1, 26).unwrap();
+    ///             } else {
+    ///                 wtr.write_bits(0, 1).unwrap();
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// let mut rdr = BitReader::new(Cursor::new(wtr.finish().unwrap()));
+    ///
+    /// let region = rdr.read_dynamic_region().unwrap();
+    /// assert_eq!(
+    ///     region.chunks[0][6][7],
+    ///     Some(DynamicChunk { plane: 1, x: 402, y: 440, rotation: 3 })
+    /// );
+    /// assert_eq!(region.chunks[0][7][6], None);
+    /// assert_eq!(region.chunks.iter().flatten().flatten().flatten().count(), 1);
+    /// ```
+    pub fn read_dynamic_region(&mut self) -> Result<DynamicRegion> {
+        let mut region = DynamicRegion {
+            chunks: [[[None; 13]; 13]; 4],
+        };
+        for plane in region.chunks.iter_mut() {
+            for column in plane.iter_mut() {
+                for chunk in column.iter_mut() {
+                    if self.read_bits(1)? == 1 {
+                        let packed = self.read_bits(26)?;
+                        *chunk = Some(DynamicChunk {
+                            plane: (packed >> 24 & 0x3) as u8,
+                            x: (packed >> 14 & 0x3FF) as u16,
+                            y: (packed >> 3 & 0x7FF) as u16,
+                            rotation: (packed >> 1 & 0x3) as u8,
+                        });
+                    }
+                }
+            }
+        }
+        Ok(region)
+    }
+
+    fn read_movement_update(&mut self, teleport: bool) -> Result<MovementUpdate> {
+        if self.read_bits(1)? == 0 {
+            return Ok(MovementUpdate {
+                movement: Movement::None,
+                update: false,
+            });
+        }
+
+        let (movement, update) = match self.read_bits(2)? {
+            0 => (Movement::None, true),
+            1 => {
+                let direction = self.read_bits(3)? as u8;
+                (Movement::Walk(direction), self.read_bits(1)? == 1)
+            }
+            2 => {
+                let walk = self.read_bits(3)? as u8;
+                let run = self.read_bits(3)? as u8;
+                (Movement::Run(walk, run), self.read_bits(1)? == 1)
+            }
+            _ if teleport => {
+                let plane = self.read_bits(2)? as u8;
+                let clear_path = self.read_bits(1)? == 1;
+                let update = self.read_bits(1)? == 1;
+                let y = self.read_bits(7)? as u8;
+                let x = self.read_bits(7)? as u8;
+                let movement = Movement::Teleport {
+                    plane,
+                    x,
+                    y,
+                    clear_path,
+                };
+                (movement, update)
+            }
+            _ => (Movement::Remove, false),
+        };
+        Ok(MovementUpdate { movement, update })
+    }
+
+    fn read_offset(&mut self) -> Result<i8> {
+        let offset = self.read_bits(5)? as i8;
+        Ok(if offset > 15 { offset - 32 } else { offset })
+    }
+}
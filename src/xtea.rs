@@ -0,0 +1,93 @@
+//! XTEA block cipher, as used for cache containers and the login block.
+//!
+//! Blocks are 8 bytes, read as two big endian dwords, and are enciphered with a 128-bit key over 32 rounds. A trailing partial block is left untouched, the same as the client.
+
+const DELTA: u32 = 0x9E3779B9;
+const ROUNDS: u32 = 32;
+
+/// Enciphers a single block of two dwords.
+///
+/// # Examples
+///
+/// ```rust
+/// use osrs_bytes::xtea;
+///
+/// assert_eq!(xtea::encipher([0, 0], &[0; 4]), [0xDEE9D4D8, 0xF7131ED9]);
+/// ```
+pub fn encipher(block: [u32; 2], key: &[u32; 4]) -> [u32; 2] {
+    let [mut v0, mut v1] = block;
+    let mut sum: u32 = 0;
+    for _ in 0..ROUNDS {
+        v0 = v0.wrapping_add(
+            ((v1 << 4 ^ v1 >> 5).wrapping_add(v1)) ^ sum.wrapping_add(key[(sum & 3) as usize]),
+        );
+        sum = sum.wrapping_add(DELTA);
+        v1 = v1.wrapping_add(
+            ((v0 << 4 ^ v0 >> 5).wrapping_add(v0))
+                ^ sum.wrapping_add(key[(sum >> 11 & 3) as usize]),
+        );
+    }
+    [v0, v1]
+}
+
+/// Deciphers a single block of two dwords.
+///
+/// # Examples
+///
+/// ```rust
+/// use osrs_bytes::xtea;
+///
+/// assert_eq!(xtea::decipher([0xDEE9D4D8, 0xF7131ED9], &[0; 4]), [0, 0]);
+/// ```
+pub fn decipher(block: [u32; 2], key: &[u32; 4]) -> [u32; 2] {
+    let [mut v0, mut v1] = block;
+    let mut sum = DELTA.wrapping_mul(ROUNDS);
+    for _ in 0..ROUNDS {
+        v1 = v1.wrapping_sub(
+            ((v0 << 4 ^ v0 >> 5).wrapping_add(v0))
+                ^ sum.wrapping_add(key[(sum >> 11 & 3) as usize]),
+        );
+        sum = sum.wrapping_sub(DELTA);
+        v0 = v0.wrapping_sub(
+            ((v1 << 4 ^ v1 >> 5).wrapping_add(v1)) ^ sum.wrapping_add(key[(sum & 3) as usize]),
+        );
+    }
+    [v0, v1]
+}
+
+/// Enciphers every whole 8-byte block of `data` in place, leaving a trailing partial block untouched.
+///
+/// # Examples
+///
+/// ```rust
+/// use osrs_bytes::xtea;
+///
+/// let key = [0x00010203, 0x04050607, 0x08090A0B, 0x0C0D0E0F];
+/// let mut data = *b"ABCDEFGHxyz";
+/// xtea::xtea_encrypt(&mut data, key);
+/// assert_eq!(data[..8], [0x49, 0x7D, 0xF3, 0xD0, 0x72, 0x61, 0x2C, 0xB5]);
+/// assert_eq!(&data[8..], b"xyz");
+///
+/// xtea::xtea_decrypt(&mut data, key);
+/// assert_eq!(&data, b"ABCDEFGHxyz");
+/// ```
+pub fn xtea_encrypt(data: &mut [u8], key: [u32; 4]) {
+    apply(data, |block| encipher(block, &key));
+}
+
+/// Deciphers every whole 8-byte block of `data` in place, leaving a trailing partial block untouched.
+pub fn xtea_decrypt(data: &mut [u8], key: [u32; 4]) {
+    apply(data, |block| decipher(block, &key));
+}
+
+fn apply(data: &mut [u8], f: impl Fn([u32; 2]) -> [u32; 2]) {
+    for chunk in data.chunks_exact_mut(8) {
+        let block = [
+            u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]),
+            u32::from_be_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]),
+        ];
+        let [v0, v1] = f(block);
+        chunk[..4].copy_from_slice(&v0.to_be_bytes());
+        chunk[4..].copy_from_slice(&v1.to_be_bytes());
+    }
+}